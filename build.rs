@@ -0,0 +1,206 @@
+//! Compile the `content/` directory into the topic registry.
+//!
+//! Every `content/*.md` file is a topic: a front-matter block delimited by `---` lines,
+//! holding `key: value` pairs, followed by the topic description. Supported keys are
+//! `title` (required), `order` (position in the list), `kind` (`text` or `quote`) and the
+//! repeatable `link`, `mail` and `download`, each holding a URL or `Label <URL>`.
+//!
+//! `content/quotes.txt` is compiled into the quotes drawn by quote topics, see its header.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+const CONTENT_DIR: &str = "content";
+const QUOTES_FILE: &str = "content/quotes.txt";
+
+struct TopicSource {
+    id: String,
+    title: String,
+    order: i64,
+    links: Vec<LinkSource>,
+    kind: String,
+    description: String,
+}
+
+struct LinkSource {
+    kind: &'static str,
+    label: String,
+    url: String,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={CONTENT_DIR}");
+
+    let mut topics: Vec<TopicSource> = fs::read_dir(CONTENT_DIR)
+        .unwrap_or_else(|e| panic!("Cannot read the {CONTENT_DIR} directory: {e}"))
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .map(|path| parse_topic(&path))
+        .collect();
+    topics.sort_by(|a, b| a.order.cmp(&b.order).then_with(|| a.id.cmp(&b.id)));
+
+    let mut registry = String::from("static TOPICS: &[TopicContent] = &[\n");
+    for topic in &topics {
+        let kind = match topic.kind.as_str() {
+            "" | "text" => "Text",
+            "quote" => "Quote",
+            other => panic!("Unknown kind '{other}' for topic '{}'", topic.id),
+        };
+        let links: Vec<String> = topic
+            .links
+            .iter()
+            .map(|link| {
+                format!(
+                    "Link {{ kind: LinkKind::{}, label: {:?}, url: {:?} }}",
+                    link.kind, link.label, link.url
+                )
+            })
+            .collect();
+        writeln!(
+            registry,
+            "    TopicContent {{ id: {:?}, title: {:?}, links: &[{}], kind: TopicKind::{kind}, description: {:?} }},",
+            topic.id,
+            topic.title,
+            links.join(", "),
+            topic.description
+        )
+        .expect("writing to a String never fails");
+    }
+    registry.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").expect("cargo always sets OUT_DIR for build scripts");
+    fs::write(Path::new(&out_dir).join("topics.rs"), registry)
+        .expect("Cannot write the topic registry");
+    fs::write(Path::new(&out_dir).join("quotes.rs"), quotes()).expect("Cannot write the quotes");
+}
+
+/// Registry of the quotes of [`QUOTES_FILE`]
+fn quotes() -> String {
+    println!("cargo:rerun-if-changed={QUOTES_FILE}");
+    let text = fs::read_to_string(QUOTES_FILE)
+        .unwrap_or_else(|e| panic!("Cannot read {QUOTES_FILE}: {e}"));
+    let lines: Vec<&str> = text.lines().filter(|line| !line.starts_with('#')).collect();
+    let mut registry = String::from("static QUOTES: &[Quote] = &[\n");
+    // Ids are the slug of the author, numbered among the quotes of the same author
+    let mut authors: Vec<String> = Vec::new();
+    for block in lines.split(|line| line.trim().is_empty()) {
+        if block.is_empty() {
+            continue;
+        }
+        let (mut text, mut author, mut source, mut year, mut tags) =
+            (None, None, None, None, Vec::new());
+        for line in block {
+            let (key, value) = line
+                .split_once(':')
+                .unwrap_or_else(|| panic!("Invalid line '{line}' in {QUOTES_FILE}"));
+            let value = value.trim().to_string();
+            match key.trim() {
+                "text" => text = Some(value),
+                "author" => author = Some(value),
+                "source" => source = Some(value),
+                "year" => {
+                    year = Some(value.parse::<i32>().unwrap_or_else(|e| {
+                        panic!("Invalid year '{value}' in {QUOTES_FILE}: {e}")
+                    }));
+                }
+                "tags" => tags = value.split(',').map(|tag| tag.trim().to_string()).collect(),
+                other => panic!("Unknown key '{other}' in {QUOTES_FILE}"),
+            }
+        }
+        let text = text.unwrap_or_else(|| panic!("A quote of {QUOTES_FILE} has no text"));
+        let author = author.unwrap_or_else(|| panic!("'{text}' has no author in {QUOTES_FILE}"));
+        let slug = slug(&author);
+        authors.push(slug.clone());
+        let number = authors.iter().filter(|other| **other == slug).count();
+        writeln!(
+            registry,
+            "    Quote {{ id: {:?}, text: {text:?}, author: {author:?}, source: {source:?}, year: {year:?}, tags: &{tags:?} }},",
+            format!("{slug}-{number}")
+        )
+        .expect("writing to a String never fails");
+    }
+    registry.push_str("];\n");
+    registry
+}
+
+/// Lowercase words of `text` joined by dashes, e.g. `edsger-w-dijkstra`
+fn slug(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn parse_topic(path: &Path) -> TopicSource {
+    println!("cargo:rerun-if-changed={}", path.display());
+    let id = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_else(|| panic!("Invalid file name {}", path.display()))
+        .to_string();
+    let text =
+        fs::read_to_string(path).unwrap_or_else(|e| panic!("Cannot read {}: {e}", path.display()));
+
+    let rest = text.strip_prefix("---\n").unwrap_or_else(|| {
+        panic!(
+            "{} must start with a '---' front-matter line",
+            path.display()
+        )
+    });
+    let (front_matter, description) = rest
+        .split_once("\n---\n")
+        .or_else(|| rest.strip_suffix("\n---").map(|fm| (fm, "")))
+        .unwrap_or_else(|| panic!("{} has an unterminated front-matter", path.display()));
+
+    let mut topic = TopicSource {
+        id,
+        title: String::new(),
+        order: i64::MAX,
+        links: Vec::new(),
+        kind: String::new(),
+        description: description.trim_end().to_string(),
+    };
+    for line in front_matter.lines().filter(|line| !line.trim().is_empty()) {
+        let (key, value) = line
+            .split_once(':')
+            .unwrap_or_else(|| panic!("Invalid front-matter line '{line}' in {}", path.display()));
+        let value = value.trim().to_string();
+        match key.trim() {
+            "title" => topic.title = value,
+            "order" => {
+                topic.order = value
+                    .parse()
+                    .unwrap_or_else(|e| panic!("Invalid order in {}: {e}", path.display()));
+            }
+            "link" => topic.links.push(parse_link("Web", &value)),
+            "mail" => topic.links.push(parse_link("Mail", &value)),
+            "download" => topic.links.push(parse_link("Download", &value)),
+            "kind" => topic.kind = value,
+            other => panic!("Unknown front-matter key '{other}' in {}", path.display()),
+        }
+    }
+    assert!(!topic.title.is_empty(), "{} has no title", path.display());
+    topic
+}
+
+/// Link of the `kind` variant from `Label <URL>`, or a bare URL labelled without its scheme
+fn parse_link(kind: &'static str, value: &str) -> LinkSource {
+    let (label, url) = value
+        .strip_suffix('>')
+        .and_then(|rest| rest.rsplit_once('<'))
+        .map_or_else(
+            || {
+                let label = value.split_once(':').map_or(value, |(_, rest)| rest);
+                (label.trim_start_matches('/'), value)
+            },
+            |(label, url)| (label.trim(), url.trim()),
+        );
+    LinkSource {
+        kind,
+        label: label.to_string(),
+        url: url.to_string(),
+    }
+}
@@ -1,15 +1,41 @@
 use crate::fps;
-use crate::utils::inject_backend_footer;
+use crate::utils::{inject_backend_footer, prepare_terminal_host};
 use ratzilla::backend::canvas::CanvasBackendOptions;
+use ratzilla::backend::cursor::CursorShape;
 use ratzilla::backend::dom::DomBackendOptions;
 use ratzilla::backend::webgl2::WebGl2BackendOptions;
 use ratzilla::ratatui::backend::Backend;
-use ratzilla::ratatui::{Terminal, TerminalOptions};
-use ratzilla::{CanvasBackend, DomBackend};
+use ratzilla::ratatui::{Frame, Terminal, TerminalOptions};
+use ratzilla::{CanvasBackend, DomBackend, WebGl2Backend};
+use std::cell::{Cell, RefCell};
 use std::convert::TryFrom;
 use std::fmt;
 use std::io;
-use web_sys::{Url, window};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+use web_sys::{HtmlCanvasElement, Url, window};
+
+/// Id of the element every backend is mounted into.
+pub const TERMINAL_HOST_ID: &str = "ratzilla-terminal";
+
+thread_local! {
+    /// Backend requested by the footer, consumed by the render loop on the next frame
+    static REQUESTED_BACKEND: Cell<Option<BackendType>> = const { Cell::new(None) };
+}
+
+/// Ask the render loop to rebuild the terminal on another backend.
+///
+/// The switch happens at the start of the next frame, see [`draw_web_hot_swappable`].
+pub fn request_backend_switch(backend_type: BackendType) {
+    REQUESTED_BACKEND.with(|requested| requested.set(Some(backend_type)));
+}
+
+/// Take the pending backend switch request, if any.
+#[must_use]
+pub fn take_backend_switch_request() -> Option<BackendType> {
+    REQUESTED_BACKEND.with(Cell::take)
+}
 
 /// Available backend types
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -17,7 +43,7 @@ pub enum BackendType {
     Dom,
     #[default]
     Canvas,
-    //WebGl2, It's kinda bugged
+    WebGl2,
 }
 
 impl BackendType {
@@ -27,7 +53,40 @@ impl BackendType {
         match self {
             Self::Dom => "dom",
             Self::Canvas => "canvas",
-            //Self::WebGl2 => "webgl2",
+            Self::WebGl2 => "webgl2",
+        }
+    }
+
+    /// Get the backend to try next when this one cannot be created.
+    ///
+    /// The chain goes from the most demanding backend to the most compatible one:
+    /// webgl2 → canvas → dom. DOM is the end of the chain and has no fallback.
+    #[must_use]
+    pub const fn fallback(&self) -> Option<Self> {
+        match self {
+            Self::WebGl2 => Some(Self::Canvas),
+            Self::Canvas => Some(Self::Dom),
+            Self::Dom => None,
+        }
+    }
+
+    /// Check whether the current browser is able to run this backend.
+    ///
+    /// WebGL2 is probed by creating a detached canvas and requesting a `webgl2` context,
+    /// the other backends only need a document to attach to.
+    #[must_use]
+    pub fn is_supported(&self) -> bool {
+        let Some(document) = window().and_then(|w| w.document()) else {
+            return false;
+        };
+        match self {
+            Self::Dom | Self::Canvas => true,
+            Self::WebGl2 => document
+                .create_element("canvas")
+                .ok()
+                .and_then(|element| element.dyn_into::<HtmlCanvasElement>().ok())
+                .and_then(|canvas| canvas.get_context("webgl2").ok().flatten())
+                .is_some(),
         }
     }
 }
@@ -39,7 +98,7 @@ impl TryFrom<String> for BackendType {
         match s.to_lowercase().as_str() {
             "dom" => Ok(Self::Dom),
             "canvas" => Ok(Self::Canvas),
-            //"webgl2" => Ok(Self::WebGl2),
+            "webgl2" => Ok(Self::WebGl2),
             _ => Err(format!(
                 "Invalid backend type: '{s}'. Valid options are: dom, canvas, webgl2"
             )),
@@ -67,7 +126,7 @@ impl fmt::Display for BackendType {
 pub enum RatzillaBackend {
     Dom(DomBackend),
     Canvas(CanvasBackend),
-    //WebGl2(WebGl2Backend),
+    WebGl2(WebGl2Backend),
 }
 
 impl RatzillaBackend {
@@ -77,7 +136,7 @@ impl RatzillaBackend {
         match self {
             Self::Dom(_) => BackendType::Dom,
             Self::Canvas(_) => BackendType::Canvas,
-            //Self::WebGl2(_) => BackendType::WebGl2,
+            Self::WebGl2(_) => BackendType::WebGl2,
         }
     }
 }
@@ -90,7 +149,7 @@ impl Backend for RatzillaBackend {
         match self {
             Self::Dom(backend) => backend.draw(content),
             Self::Canvas(backend) => backend.draw(content),
-            //Self::WebGl2(backend) => backend.draw(content),
+            Self::WebGl2(backend) => backend.draw(content),
         }
     }
 
@@ -98,7 +157,7 @@ impl Backend for RatzillaBackend {
         match self {
             Self::Dom(backend) => backend.append_lines(n),
             Self::Canvas(backend) => backend.append_lines(n),
-            //Self::WebGl2(backend) => backend.append_lines(n),
+            Self::WebGl2(backend) => backend.append_lines(n),
         }
     }
 
@@ -106,7 +165,7 @@ impl Backend for RatzillaBackend {
         match self {
             Self::Dom(backend) => backend.hide_cursor(),
             Self::Canvas(backend) => backend.hide_cursor(),
-            //Self::WebGl2(backend) => backend.hide_cursor(),
+            Self::WebGl2(backend) => backend.hide_cursor(),
         }
     }
 
@@ -114,7 +173,7 @@ impl Backend for RatzillaBackend {
         match self {
             Self::Dom(backend) => backend.show_cursor(),
             Self::Canvas(backend) => backend.show_cursor(),
-            //Self::WebGl2(backend) => backend.show_cursor(),
+            Self::WebGl2(backend) => backend.show_cursor(),
         }
     }
 
@@ -122,7 +181,7 @@ impl Backend for RatzillaBackend {
         match self {
             Self::Dom(backend) => backend.get_cursor_position(),
             Self::Canvas(backend) => backend.get_cursor_position(),
-            //Self::WebGl2(backend) => backend.get_cursor_position(),
+            Self::WebGl2(backend) => backend.get_cursor_position(),
         }
     }
 
@@ -133,7 +192,7 @@ impl Backend for RatzillaBackend {
         match self {
             Self::Dom(backend) => backend.set_cursor_position(position),
             Self::Canvas(backend) => backend.set_cursor_position(position),
-            //Self::WebGl2(backend) => backend.set_cursor_position(position),
+            Self::WebGl2(backend) => backend.set_cursor_position(position),
         }
     }
 
@@ -141,7 +200,7 @@ impl Backend for RatzillaBackend {
         match self {
             Self::Dom(backend) => backend.clear(),
             Self::Canvas(backend) => backend.clear(),
-            //Self::WebGl2(backend) => backend.clear(),
+            Self::WebGl2(backend) => backend.clear(),
         }
     }
 
@@ -149,7 +208,7 @@ impl Backend for RatzillaBackend {
         match self {
             Self::Dom(backend) => backend.size(),
             Self::Canvas(backend) => backend.size(),
-            //Self::WebGl2(backend) => backend.size(),
+            Self::WebGl2(backend) => backend.size(),
         }
     }
 
@@ -157,7 +216,7 @@ impl Backend for RatzillaBackend {
         match self {
             Self::Dom(backend) => backend.window_size(),
             Self::Canvas(backend) => backend.window_size(),
-            //Self::WebGl2(backend) => backend.window_size(),
+            Self::WebGl2(backend) => backend.window_size(),
         }
     }
 
@@ -165,7 +224,7 @@ impl Backend for RatzillaBackend {
         match self {
             Self::Dom(backend) => backend.flush(),
             Self::Canvas(backend) => backend.flush(),
-            //Self::WebGl2(backend) => backend.flush(),
+            Self::WebGl2(backend) => backend.flush(),
         }
     }
 }
@@ -265,12 +324,18 @@ impl Backend for FpsTrackingBackend {
 /// The builder uses the following priority order for backend selection:
 /// 1. `?backend=<type>` URL query parameter (dom, canvas, or webgl2)
 /// 2. Fallback backend specified in `with_fallback()`
-/// 3. Default backend (DOM)
+/// 3. Default backend (Canvas)
 ///
-/// # Example
+/// If the selected backend is not supported by the browser or fails to initialize,
+/// the next one in the chain returned by [`BackendType::fallback`] is tried instead.
 ///
-/// ```rust
+/// # Example
 ///
+/// ```rust,no_run
+/// # use ratzilla::backend::canvas::CanvasBackendOptions;
+/// # use ratzilla::backend::webgl2::WebGl2BackendOptions;
+/// # use website::backend::{BackendType, MultiBackendBuilder};
+/// # fn main() -> std::io::Result<()> {
 /// let terminal = MultiBackendBuilder::with_fallback(BackendType::Dom)
 ///     .canvas_options(CanvasBackendOptions::new().grid_id("terminal-id"))
 ///     .webgl2_options(WebGl2BackendOptions::new().size((1200, 800)))
@@ -278,8 +343,10 @@ impl Backend for FpsTrackingBackend {
 ///
 /// // Get backend type if needed
 /// let backend_type = terminal.backend().backend_type();
+/// # Ok(())
+/// # }
 /// ```
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct MultiBackendBuilder {
     default_backend: BackendType,
 
@@ -289,6 +356,23 @@ pub struct MultiBackendBuilder {
     webgl2_options: WebGl2BackendOptions,
 }
 
+impl Default for MultiBackendBuilder {
+    /// Every backend is mounted inside the [`TERMINAL_HOST_ID`] element by default,
+    /// so that it can be torn down when switching backend at runtime.
+    fn default() -> Self {
+        Self {
+            default_backend: BackendType::default(),
+            terminal_options: TerminalOptions::default(),
+            canvas_options: CanvasBackendOptions::new().grid_id(TERMINAL_HOST_ID),
+            dom_options: DomBackendOptions::new(
+                Some(TERMINAL_HOST_ID.to_string()),
+                CursorShape::default(),
+            ),
+            webgl2_options: WebGl2BackendOptions::new().grid_id(TERMINAL_HOST_ID),
+        }
+    }
+}
+
 impl MultiBackendBuilder {
     /// Create a new builder with the specified fallback backend type.
     ///
@@ -344,7 +428,8 @@ impl MultiBackendBuilder {
     ///
     /// This method:
     /// 1. Determines the backend type from URL query parameters or fallback
-    /// 2. Creates the appropriate backend with the configured options
+    /// 2. Creates the appropriate backend with the configured options, walking the
+    ///    fallback chain (webgl2 → canvas → dom) if the backend is unsupported or fails
     /// 3. Wraps the backend with FPS tracking
     /// 4. Creates and returns the terminal with the selected backend
     /// 5. Injects a backend footer into the DOM (best effort)
@@ -356,26 +441,44 @@ impl MultiBackendBuilder {
     ///
     /// # Errors
     ///
-    /// Returns an error if backend creation or terminal initialization fails.
+    /// Returns an error if every backend in the fallback chain fails to be created,
+    /// or if terminal initialization fails.
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
+    /// # use website::backend::{BackendType, MultiBackendBuilder};
+    /// # fn main() -> std::io::Result<()> {
     /// let terminal = MultiBackendBuilder::with_fallback(BackendType::Canvas)
     ///     .build_terminal()?;
     ///
     /// // Get backend type if needed
     /// let backend_type = terminal.backend().backend_type();
     /// println!("Using {backend_type} backend");
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn build_terminal(self) -> io::Result<Terminal<FpsTrackingBackend>> {
         let backend_type = parse_backend_from_url(self.default_backend);
-        let backend = create_backend_with_options(
-            backend_type,
-            Some(self.dom_options),
-            Some(self.canvas_options),
-            //Some(self.webgl2_options),
-        )?;
+        self.build_terminal_for(backend_type)
+    }
+
+    /// Build the terminal on the given backend type, ignoring URL query parameters.
+    ///
+    /// The terminal host element is emptied first, so any previously mounted backend
+    /// is removed from the page. The fallback chain still applies if the backend fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if every backend in the fallback chain fails to be created,
+    /// or if terminal initialization fails.
+    pub fn build_terminal_for(
+        mut self,
+        backend_type: BackendType,
+    ) -> io::Result<Terminal<FpsTrackingBackend>> {
+        prepare_terminal_host().map_err(|e| io::Error::other(format!("{e:?}")))?;
+        let backend = self.create_backend_with_fallback(backend_type)?;
+        let backend_type = backend.backend_type();
 
         // Initialize FPS recorder
         fps::init_fps_recorder();
@@ -389,6 +492,52 @@ impl MultiBackendBuilder {
 
         Ok(terminal)
     }
+
+    /// Create the requested backend, falling back along the chain on failure.
+    ///
+    /// Backends that fail the capability probe are skipped without being created.
+    /// Each backend is attempted at most once, so its options are moved out of the builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last creation error if no backend in the chain could be created.
+    fn create_backend_with_fallback(
+        &mut self,
+        requested: BackendType,
+    ) -> io::Result<RatzillaBackend> {
+        let mut last_error = None;
+        let mut candidate = Some(requested);
+        while let Some(backend_type) = candidate {
+            if backend_type.is_supported() {
+                // Only the options of this backend are taken, the fallbacks keep theirs
+                let (dom_options, canvas_options, webgl2_options) = match backend_type {
+                    BackendType::Dom => (Some(std::mem::take(&mut self.dom_options)), None, None),
+                    BackendType::Canvas => {
+                        (None, Some(std::mem::take(&mut self.canvas_options)), None)
+                    }
+                    BackendType::WebGl2 => {
+                        (None, None, Some(std::mem::take(&mut self.webgl2_options)))
+                    }
+                };
+                match create_backend_with_options(
+                    backend_type,
+                    dom_options,
+                    canvas_options,
+                    webgl2_options,
+                ) {
+                    Ok(backend) => return Ok(backend),
+                    Err(e) => last_error = Some(e),
+                }
+            }
+            candidate = backend_type.fallback();
+        }
+        Err(last_error.unwrap_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("No supported backend found starting from {requested}"),
+            )
+        }))
+    }
 }
 
 impl From<BackendType> for MultiBackendBuilder {
@@ -434,9 +583,9 @@ fn create_backend_with_options(
     backend_type: BackendType,
     dom_options: Option<DomBackendOptions>,
     canvas_options: Option<CanvasBackendOptions>,
-    //webgl2_options: Option<WebGl2BackendOptions>,
+    webgl2_options: Option<WebGl2BackendOptions>,
 ) -> io::Result<RatzillaBackend> {
-    use RatzillaBackend::{Canvas, Dom};
+    use RatzillaBackend::{Canvas, Dom, WebGl2};
 
     match backend_type {
         BackendType::Dom => Ok(Dom(DomBackend::new_with_options(
@@ -445,8 +594,61 @@ fn create_backend_with_options(
         BackendType::Canvas => Ok(Canvas(CanvasBackend::new_with_options(
             canvas_options.unwrap_or_default(),
         )?)),
-        //BackendType::WebGl2 => Ok(WebGl2(WebGl2Backend::new_with_options(
-        //    webgl2_options.unwrap_or_default(),
-        //)?)),
+        BackendType::WebGl2 => Ok(WebGl2(WebGl2Backend::new_with_options(
+            webgl2_options.unwrap_or_default(),
+        )?)),
+    }
+}
+
+/// Render the terminal on the web, rebuilding it whenever a backend switch is requested.
+///
+/// This mirrors `WebRenderer::draw_web`, but keeps ownership of the terminal so that it can
+/// be replaced between two frames. The `builder` factory is called for every switch because
+/// backend options cannot be reused once consumed. The render callback, and therefore any
+/// state it captures, survives the switch untouched.
+///
+/// If the new terminal cannot be built, the previous backend is rebuilt in its place.
+pub fn draw_web_hot_swappable<B, F>(
+    terminal: Terminal<FpsTrackingBackend>,
+    builder: B,
+    mut render_callback: F,
+) where
+    B: Fn() -> MultiBackendBuilder + 'static,
+    F: FnMut(&mut Frame<'_>) + 'static,
+{
+    let mut terminal = terminal;
+    let callback = Rc::new(RefCell::new(None::<Closure<dyn FnMut()>>));
+    *callback.borrow_mut() = Some(Closure::new({
+        let callback = callback.clone();
+        move || {
+            if let Some(requested) = take_backend_switch_request() {
+                let current = terminal.backend().backend_type();
+                if requested != current {
+                    match builder()
+                        .build_terminal_for(requested)
+                        .or_else(|_| builder().build_terminal_for(current))
+                    {
+                        Ok(new_terminal) => terminal = new_terminal,
+                        Err(e) => web_sys::console::error_1(&e.to_string().into()),
+                    }
+                }
+            }
+            if let Err(e) = terminal.draw(&mut render_callback) {
+                web_sys::console::error_1(&e.to_string().into());
+            }
+            if let Some(closure) = callback.borrow().as_ref() {
+                request_animation_frame(closure);
+            }
+        }
+    }));
+    if let Some(closure) = callback.borrow().as_ref() {
+        request_animation_frame(closure);
+    }
+}
+
+/// Schedule the given closure for the next animation frame.
+fn request_animation_frame(closure: &Closure<dyn FnMut()>) {
+    if let Some(w) = window() {
+        let _ = w.request_animation_frame(closure.as_ref().unchecked_ref());
     }
 }
@@ -0,0 +1,98 @@
+//! Copy text to the visitor clipboard.
+//!
+//! Selecting text does not work on the canvas backend, so the topic list copies for the
+//! visitor. The asynchronous Clipboard API is only available on secure pages and may be
+//! refused, a hidden textarea copied with `execCommand` takes over in both cases.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Outcome of a copy, known once the browser has answered.
+#[derive(Debug, Clone, Default)]
+pub struct PendingCopy {
+    copied: Rc<Cell<Option<bool>>>,
+}
+
+impl PendingCopy {
+    /// Whether the text was copied, `None` while the browser has not answered.
+    #[must_use]
+    pub fn result(&self) -> Option<bool> {
+        self.copied.get()
+    }
+
+    fn resolve(&self, copied: bool) {
+        self.copied.set(Some(copied));
+    }
+}
+
+/// Copy `text` to the clipboard.
+#[cfg(target_arch = "wasm32")]
+#[must_use]
+pub fn copy(text: &str) -> PendingCopy {
+    use ratzilla::web_sys;
+    use wasm_bindgen::closure::Closure;
+
+    let pending = PendingCopy::default();
+    let clipboard = web_sys::window().map(|window| window.navigator().clipboard());
+    match clipboard {
+        // The Clipboard API is undefined on insecure pages
+        Some(clipboard) if !clipboard.is_undefined() => {
+            let on_copied = Closure::once({
+                let pending = pending.clone();
+                move |_| pending.resolve(true)
+            });
+            let on_refused = Closure::once({
+                let pending = pending.clone();
+                let text = text.to_string();
+                move |_| pending.resolve(copy_with_textarea(&text).is_ok())
+            });
+            let _ = clipboard.write_text(text).then2(&on_copied, &on_refused);
+            // The browser calls one of them later, they live as long as the page
+            on_copied.forget();
+            on_refused.forget();
+        }
+        _ => pending.resolve(copy_with_textarea(text).is_ok()),
+    }
+    pending
+}
+
+/// Native terminals have no clipboard to copy to, every copy fails.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn copy(_text: &str) -> PendingCopy {
+    let pending = PendingCopy::default();
+    pending.resolve(false);
+    pending
+}
+
+/// Copy `text` through a textarea, selected out of sight for the time of the copy
+#[cfg(target_arch = "wasm32")]
+fn copy_with_textarea(text: &str) -> Result<(), ratzilla::web_sys::wasm_bindgen::JsValue> {
+    use ratzilla::web_sys;
+    use wasm_bindgen::JsCast;
+
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("No document")?;
+    let textarea: web_sys::HtmlTextAreaElement = document.create_element("textarea")?.dyn_into()?;
+    textarea.set_value(text);
+    textarea.set_attribute("readonly", "")?;
+    textarea.set_attribute("aria-hidden", "true")?;
+    textarea.set_attribute(
+        "style",
+        "position: fixed; top: 0; left: -9999px; opacity: 0;",
+    )?;
+    let body = document.body().ok_or("No body")?;
+    body.append_child(&textarea)?;
+    textarea.select();
+    let copied = document
+        .dyn_into::<web_sys::HtmlDocument>()
+        .map_err(|_| "No HTML document")?
+        .exec_command("copy");
+    textarea.remove();
+    if copied? {
+        Ok(())
+    } else {
+        Err("The browser refused to copy".into())
+    }
+}
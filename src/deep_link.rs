@@ -0,0 +1,94 @@
+//! Reflect the selected topic in the URL hash, so that sections can be shared as links.
+//!
+//! `#about` selects the about topic on load, every selection change is pushed to the browser
+//! history and going back or forward moves the selection accordingly.
+
+use crate::models::topic::Topic;
+use ratzilla::web_sys;
+use ratzilla::web_sys::wasm_bindgen::JsValue;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+
+/// Keeps the URL hash in sync with the selected topic.
+#[derive(Default)]
+pub struct DeepLink {
+    /// Topic the current history entry points to
+    current: Option<Topic>,
+    /// Whether [`DeepLink::sync`] was called already, the first call only catches up with
+    /// the restored visit
+    synced: bool,
+}
+
+impl DeepLink {
+    /// Read the topic linked by the current URL, if any.
+    #[must_use]
+    pub fn from_url() -> (Self, Option<Topic>) {
+        let current = topic_from_hash();
+        let deep_link = Self {
+            current,
+            synced: false,
+        };
+        (deep_link, current)
+    }
+
+    /// Push a history entry when the selected topic changed since the last call.
+    ///
+    /// The first call replaces the entry the page was loaded with instead, the topic of a
+    /// restored visit was not selected by the visitor and going back must leave the website.
+    pub fn sync(&mut self, selected: Option<Topic>) {
+        let replace = !std::mem::replace(&mut self.synced, true);
+        if selected == self.current {
+            return;
+        }
+        self.current = selected;
+        if let Err(err) = set_history_entry(selected, replace) {
+            web_sys::console::warn_1(&err);
+        }
+    }
+
+    /// Call `on_change` with the linked topic when the visitor goes back or forward.
+    ///
+    /// The linked topic becomes the current one, it must be selected by the callback
+    /// or the next [`DeepLink::sync`] pushes a new entry.
+    pub fn on_history_change<F>(deep_link: &Rc<RefCell<Self>>, mut on_change: F)
+    where
+        F: FnMut(Option<Topic>) + 'static,
+    {
+        let deep_link = deep_link.clone();
+        let on_pop_state = Closure::<dyn FnMut(_)>::new(move |_: web_sys::Event| {
+            let topic = topic_from_hash();
+            deep_link.borrow_mut().current = topic;
+            on_change(topic);
+        });
+        if let Some(window) = web_sys::window() {
+            let _ = window.add_event_listener_with_callback(
+                "popstate",
+                on_pop_state.as_ref().unchecked_ref(),
+            );
+        }
+        on_pop_state.forget();
+    }
+}
+
+fn topic_from_hash() -> Option<Topic> {
+    let hash = web_sys::window()?.location().hash().ok()?;
+    Topic::from_id(hash.trim_start_matches('#'))
+}
+
+fn set_history_entry(topic: Option<Topic>, replace: bool) -> Result<(), JsValue> {
+    let window = web_sys::window().ok_or("No window")?;
+    let location = window.location();
+    // Without a topic the hash is dropped, the path and query are kept
+    let url = topic.map_or_else(
+        || Ok::<_, JsValue>(format!("{}{}", location.pathname()?, location.search()?)),
+        |topic| Ok(format!("#{}", topic.id())),
+    )?;
+    let history = window.history()?;
+    if replace {
+        history.replace_state_with_url(&JsValue::NULL, "", Some(&url))
+    } else {
+        history.push_state_with_url(&JsValue::NULL, "", Some(&url))
+    }
+}
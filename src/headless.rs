@@ -0,0 +1,137 @@
+//! Render scenes without a browser or a terminal, into an in-memory buffer.
+//!
+//! This is what the snapshot tests use to catch layout regressions, the returned buffer
+//! exposes every styled cell and [`buffer_to_text`] turns it into plain text.
+//!
+//! Rendering a given scene leaves the scene stack as it is: the scene is the one on the
+//! stack, with the popups over it, or a new one only made for the render.
+
+use crate::scenes::{IntroScene, ListScene, QuotesScene, ShellScene};
+use crate::state::{State, ui};
+use ratzilla::ratatui::Frame;
+use ratzilla::ratatui::Terminal;
+use ratzilla::ratatui::backend::TestBackend;
+use ratzilla::ratatui::buffer::Buffer;
+use ratzilla::ratatui::style::{Color, Modifier};
+use std::fmt::Write;
+
+/// Render the intro scene of `state` on a `width` x `height` terminal.
+#[must_use]
+pub fn render_intro_to_buffer(state: &mut State, width: u16, height: u16) -> Buffer {
+    render_to_buffer(width, height, |f| {
+        state.render_scene(f, |ctx| IntroScene::new(ctx.motion));
+    })
+}
+
+/// Render the menu scene of `state` on a `width` x `height` terminal.
+#[must_use]
+pub fn render_menu_to_buffer(state: &mut State, width: u16, height: u16) -> Buffer {
+    render_to_buffer(width, height, |f| state.render_scene(f, |_| ListScene))
+}
+
+/// Render the shell scene of `state` on a `width` x `height` terminal.
+#[must_use]
+pub fn render_shell_to_buffer(state: &mut State, width: u16, height: u16) -> Buffer {
+    render_to_buffer(width, height, |f| state.render_scene(f, |_| ShellScene))
+}
+
+/// Render the quote book of `state` on a `width` x `height` terminal.
+#[must_use]
+pub fn render_quotes_to_buffer(state: &mut State, width: u16, height: u16) -> Buffer {
+    render_to_buffer(width, height, |f| {
+        state.render_scene(f, |ctx| QuotesScene::new(ctx.app.quote()));
+    })
+}
+
+/// Render the current scene of `state` on a `width` x `height` terminal, as frontends do.
+#[must_use]
+pub fn render_to_buffer_with_ui(state: &mut State, width: u16, height: u16) -> Buffer {
+    render_to_buffer(width, height, |f| ui(f, state))
+}
+
+/// Plain text content of the buffer, one line per row with trailing spaces trimmed.
+///
+/// Wide characters take two cells in the buffer, the second one is skipped so that the
+/// text looks like it does on screen.
+#[must_use]
+pub fn buffer_to_text(buffer: &Buffer) -> String {
+    let width = usize::from(buffer.area.width);
+    if width == 0 {
+        return String::new();
+    }
+    buffer
+        .content
+        .chunks(width)
+        .map(|row| {
+            let mut line = String::new();
+            let mut skip = 0;
+            for cell in row {
+                if skip > 0 {
+                    skip -= 1;
+                    continue;
+                }
+                let symbol = cell.symbol();
+                skip = unicode_width(symbol).saturating_sub(1);
+                line.push_str(symbol);
+            }
+            line.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Text content of the buffer split in runs of cells sharing the same style.
+///
+/// Each row is listed on its own line as `{fg bg modifiers}"text"` runs, so that a snapshot
+/// catches colour regressions as well as layout ones. Only the foreground, background and
+/// modifiers are part of the output, they are the only style attributes the website uses.
+#[must_use]
+pub fn buffer_to_styled_text(buffer: &Buffer) -> String {
+    let width = usize::from(buffer.area.width);
+    if width == 0 {
+        return String::new();
+    }
+    buffer
+        .content
+        .chunks(width)
+        .map(|row| {
+            let mut line = String::new();
+            let mut current = None;
+            let mut run = String::new();
+            for cell in row {
+                let style = (cell.fg, cell.bg, cell.modifier);
+                if current != Some(style) {
+                    push_styled_run(&mut line, current, &run);
+                    current = Some(style);
+                    run.clear();
+                }
+                run.push_str(cell.symbol());
+            }
+            push_styled_run(&mut line, current, &run);
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn push_styled_run(line: &mut String, style: Option<(Color, Color, Modifier)>, run: &str) {
+    if let Some((fg, bg, modifier)) = style {
+        let _ = write!(line, "{{{fg} {bg} {modifier:?}}}{run:?}");
+    }
+}
+
+fn render_to_buffer<F>(width: u16, height: u16, render: F) -> Buffer
+where
+    F: FnOnce(&mut Frame<'_>),
+{
+    let mut terminal = Terminal::new(TestBackend::new(width, height))
+        .expect("the test backend never fails to be created");
+    terminal
+        .draw(render)
+        .expect("the test backend never fails to draw");
+    terminal.backend().buffer().clone()
+}
+
+fn unicode_width(symbol: &str) -> usize {
+    ratzilla::ratatui::text::Span::raw(symbol).width()
+}
@@ -0,0 +1,382 @@
+//! Key bindings of the topic list, declared once per preset.
+//!
+//! The keymap drives the dispatch of key events, the key help under the list and the `?`
+//! popup listing every binding. Every preset shares the arrows and a few letters, on top of
+//! which it adds its own movement keys. The preset is picked with the `?keymap=` URL parameter
+//! and `p` cycles through every preset.
+//!
+//! Browsers bind many Ctrl chords themselves, e.g. `Ctrl+K` focuses the search bar. The chords
+//! a preset binds are kept from the browser by [`guard_chords`], except the ones browsers never
+//! hand over to pages: `Ctrl+N`, `Ctrl+T` and `Ctrl+W` open or close windows and tabs, so the
+//! emacs `Ctrl+N` only works in native terminals.
+
+use crate::utils::url_param;
+use ratzilla::event::{KeyCode, KeyEvent};
+use ratzilla::web_sys;
+use ratzilla::web_sys::wasm_bindgen::JsValue;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+
+/// What a key does in the topic list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Up,
+    Down,
+    /// Unselect the topic, or close it on narrow screens
+    Back,
+    ToggleStatus,
+    /// Open the topic on narrow screens, change its status on the others
+    Read,
+    OpenLink,
+    /// Focus another link of the topic, the one [`Action::OpenLink`] opens
+    NextLink,
+    PreviousLink,
+    /// Copy the focused link, or the whole topic
+    Copy,
+    /// Scroll the description of the selected topic by a row
+    ScrollUp,
+    ScrollDown,
+    /// Scroll the description of the selected topic by a page
+    PageUp,
+    PageDown,
+    First,
+    Last,
+    Shell,
+    /// Open the quote book
+    Quotes,
+    Find,
+    NextTheme,
+    NextMotion,
+    NextKeymap,
+    Help,
+    Intro,
+    Quit,
+}
+
+/// Rows of the help popup, the actions of a row show their keys side by side
+pub const HELP: [(&[Action], &str); 19] = [
+    (
+        &[Action::Up, Action::Down],
+        "Select the previous or next topic",
+    ),
+    (
+        &[Action::First, Action::Last],
+        "Select the first or last topic",
+    ),
+    (
+        &[Action::Back],
+        "Unselect the topic, or go back to the list",
+    ),
+    (&[Action::ToggleStatus], "Mark the topic as read or to read"),
+    (&[Action::Read], "Read the topic, or change its status"),
+    (
+        &[Action::ScrollUp, Action::ScrollDown],
+        "Scroll the topic up or down",
+    ),
+    (
+        &[Action::PageUp, Action::PageDown],
+        "Scroll the topic by a page",
+    ),
+    (
+        &[Action::NextLink, Action::PreviousLink],
+        "Focus the next or previous link",
+    ),
+    (&[Action::OpenLink], "Open the focused link, or the first"),
+    (&[Action::Copy], "Copy the focused link, or the topic"),
+    (&[Action::Shell], "Open the shell"),
+    (&[Action::Quotes], "Browse every quote"),
+    (&[Action::Find], "Find a topic by name or content"),
+    (&[Action::NextTheme], "Switch to the next theme"),
+    (&[Action::NextMotion], "Switch to the next motion policy"),
+    (&[Action::NextKeymap], "Switch to the next key preset"),
+    (&[Action::Help], "Show or hide this help"),
+    (&[Action::Intro], "Go back to the intro"),
+    (&[Action::Quit], "Quit"),
+];
+
+/// A key with its modifiers, shift only counts for keys that are not characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Key {
+    pub code: KeyCode,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl Key {
+    const fn plain(code: KeyCode) -> Self {
+        Self {
+            code,
+            ctrl: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    const fn char(c: char) -> Self {
+        Self::plain(KeyCode::Char(c))
+    }
+
+    const fn ctrl(code: KeyCode) -> Self {
+        Self {
+            ctrl: true,
+            ..Self::plain(code)
+        }
+    }
+
+    const fn alt(c: char) -> Self {
+        Self {
+            alt: true,
+            ..Self::char(c)
+        }
+    }
+
+    const fn shift(code: KeyCode) -> Self {
+        Self {
+            shift: true,
+            ..Self::plain(code)
+        }
+    }
+
+    fn matches(&self, event: &KeyEvent) -> bool {
+        // Shift changes the character itself, e.g. `?` or `G`
+        let shift = matches!(self.code, KeyCode::Char(_)) || self.shift == event.shift;
+        self.code == event.code && self.ctrl == event.ctrl && self.alt == event.alt && shift
+    }
+
+    /// How the key is written in the help.
+    #[must_use]
+    pub fn label(&self) -> String {
+        let code = match &self.code {
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::F(n) => format!("F{n}"),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            code => format!("{code:?}"),
+        };
+        let ctrl = if self.ctrl { "Ctrl+" } else { "" };
+        let alt = if self.alt { "Alt+" } else { "" };
+        let shift = if self.shift { "Shift+" } else { "" };
+        format!("{ctrl}{alt}{shift}{code}")
+    }
+}
+
+type Bindings = &'static [(Action, &'static [Key])];
+
+/// Bindings of every preset, after the ones of the preset
+const COMMON: Bindings = &[
+    (Action::Up, &[Key::plain(KeyCode::Up)]),
+    (Action::Down, &[Key::plain(KeyCode::Down)]),
+    (Action::Back, &[Key::plain(KeyCode::Left)]),
+    (Action::ToggleStatus, &[Key::plain(KeyCode::Right)]),
+    (Action::Read, &[Key::plain(KeyCode::Enter)]),
+    (Action::OpenLink, &[Key::ctrl(KeyCode::Enter)]),
+    (Action::NextLink, &[Key::plain(KeyCode::Tab)]),
+    (Action::PreviousLink, &[Key::shift(KeyCode::Tab)]),
+    (
+        Action::Copy,
+        &[Key::char('y'), Key::ctrl(KeyCode::Char('c'))],
+    ),
+    (Action::ScrollUp, &[Key::shift(KeyCode::Up)]),
+    (Action::ScrollDown, &[Key::shift(KeyCode::Down)]),
+    (Action::PageUp, &[Key::plain(KeyCode::PageUp)]),
+    (Action::PageDown, &[Key::plain(KeyCode::PageDown)]),
+    (Action::First, &[Key::plain(KeyCode::Home)]),
+    (Action::Last, &[Key::plain(KeyCode::End)]),
+    (Action::Shell, &[Key::char(':')]),
+    (Action::Quotes, &[Key::char('"')]),
+    (
+        Action::Find,
+        &[Key::char('/'), Key::ctrl(KeyCode::Char('k'))],
+    ),
+    (Action::NextTheme, &[Key::char('t')]),
+    (Action::NextMotion, &[Key::char('m')]),
+    (Action::NextKeymap, &[Key::char('p')]),
+    (Action::Help, &[Key::char('?')]),
+    (Action::Intro, &[Key::plain(KeyCode::Esc)]),
+    (Action::Quit, &[Key::char('q')]),
+];
+
+/// A preset of key bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Keymap {
+    /// Name used by the `?keymap=` URL parameter
+    pub name: &'static str,
+    bindings: Bindings,
+}
+
+/// Arrows only
+pub const ARROWS: Keymap = Keymap {
+    name: "arrows",
+    bindings: &[],
+};
+
+/// The left hand on WASD, the historical bindings of the website
+pub const WASD: Keymap = Keymap {
+    name: "wasd",
+    bindings: &[
+        (Action::Up, &[Key::char('w')]),
+        (Action::Down, &[Key::char('s')]),
+        (Action::Back, &[Key::char('a')]),
+        (Action::ToggleStatus, &[Key::char('d')]),
+        (Action::ScrollUp, &[Key::char('W')]),
+        (Action::ScrollDown, &[Key::char('S')]),
+        (Action::First, &[Key::char('h')]),
+        (Action::Last, &[Key::char('e')]),
+    ],
+};
+
+pub const VIM: Keymap = Keymap {
+    name: "vim",
+    bindings: &[
+        (Action::Up, &[Key::char('k')]),
+        (Action::Down, &[Key::char('j')]),
+        (Action::Back, &[Key::char('h')]),
+        (Action::ToggleStatus, &[Key::char('l')]),
+        (Action::ScrollUp, &[Key::ctrl(KeyCode::Char('y'))]),
+        (Action::ScrollDown, &[Key::ctrl(KeyCode::Char('e'))]),
+        (Action::PageUp, &[Key::ctrl(KeyCode::Char('u'))]),
+        (Action::PageDown, &[Key::ctrl(KeyCode::Char('d'))]),
+        (Action::First, &[Key::char('g')]),
+        (Action::Last, &[Key::char('G')]),
+    ],
+};
+
+pub const EMACS: Keymap = Keymap {
+    name: "emacs",
+    bindings: &[
+        (Action::Up, &[Key::ctrl(KeyCode::Char('p'))]),
+        (Action::Down, &[Key::ctrl(KeyCode::Char('n'))]),
+        (Action::Back, &[Key::ctrl(KeyCode::Char('b'))]),
+        (Action::ToggleStatus, &[Key::ctrl(KeyCode::Char('f'))]),
+        (Action::PageUp, &[Key::alt('v')]),
+        (Action::PageDown, &[Key::ctrl(KeyCode::Char('v'))]),
+        (Action::First, &[Key::alt('<')]),
+        (Action::Last, &[Key::alt('>')]),
+        (Action::Intro, &[Key::ctrl(KeyCode::Char('g'))]),
+    ],
+};
+
+/// Every preset, in the order `p` cycles through them
+pub const KEYMAPS: [Keymap; 4] = [WASD, ARROWS, VIM, EMACS];
+
+impl Default for Keymap {
+    fn default() -> Self {
+        WASD
+    }
+}
+
+impl Keymap {
+    /// Find a preset by name, case insensitive.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        KEYMAPS
+            .into_iter()
+            .find(|keymap| keymap.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Preset asked by the `?keymap=` URL parameter, or the default one.
+    #[must_use]
+    pub fn from_browser() -> Self {
+        url_param("keymap")
+            .and_then(|name| Self::from_name(&name))
+            .unwrap_or_default()
+    }
+
+    /// The preset after this one, back to the first after the last.
+    #[must_use]
+    pub fn next(&self) -> Self {
+        let index = KEYMAPS
+            .iter()
+            .position(|keymap| keymap == self)
+            .unwrap_or(0);
+        KEYMAPS[(index + 1) % KEYMAPS.len()]
+    }
+
+    /// Action bound to the key of `event`, if any.
+    #[must_use]
+    pub fn action(&self, event: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .iter()
+            .chain(COMMON)
+            .find(|(_, keys)| keys.iter().any(|key| key.matches(event)))
+            .map(|(action, _)| *action)
+    }
+
+    /// Whether the key of `event` is a Ctrl or Alt chord bound to an action, which the browser
+    /// must not run as well.
+    #[must_use]
+    pub fn claims(&self, event: &KeyEvent) -> bool {
+        (event.ctrl || event.alt) && self.action(event).is_some()
+    }
+
+    /// Keys bound to `action`, the common arrows first.
+    pub fn keys(&self, action: Action) -> impl Iterator<Item = &'static Key> {
+        COMMON
+            .iter()
+            .chain(self.bindings)
+            .filter(move |(bound, _)| *bound == action)
+            .flat_map(|(_, keys)| keys.iter())
+    }
+
+    /// Keys of `actions` side by side, e.g. `↓↑ or sw` for moving down and up.
+    ///
+    /// Single characters are written together, the keys of the preset come after the common
+    /// ones.
+    #[must_use]
+    pub fn keys_label(&self, actions: &[Action]) -> String {
+        let keys: Vec<Vec<String>> = actions
+            .iter()
+            .map(|action| self.keys(*action).map(Key::label).collect())
+            .collect();
+        let alternatives = keys.iter().map(Vec::len).min().unwrap_or(0);
+        let keys: Vec<String> = (0..alternatives)
+            .map(|i| {
+                let labels: Vec<&str> = keys.iter().map(|labels| labels[i].as_str()).collect();
+                let separator = if labels.iter().all(|l| l.chars().count() == 1) {
+                    ""
+                } else {
+                    "/"
+                };
+                labels.join(separator)
+            })
+            .collect();
+        keys.join(" or ")
+    }
+
+    /// Key help of the given actions, e.g. `↓↑ or sw to move`.
+    #[must_use]
+    pub fn hint(&self, hints: &[(&[Action], &str)]) -> String {
+        let hints: Vec<String> = hints
+            .iter()
+            .map(|(actions, what)| format!("{} to {what}", self.keys_label(actions)))
+            .collect();
+        hints.join(", ")
+    }
+}
+
+/// Keep the browser from running the chords claimed by the keymap given by `keymap`.
+///
+/// The keymap is asked on every key press, so that switching presets moves the guard along.
+///
+/// # Errors
+///
+/// Returns an error if the document cannot be found.
+pub fn guard_chords<K>(keymap: K) -> Result<(), JsValue>
+where
+    K: Fn() -> Keymap + 'static,
+{
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("No document")?;
+    let listener = Closure::<dyn FnMut(_)>::new(move |event: web_sys::KeyboardEvent| {
+        if keymap().claims(&event.clone().into()) {
+            event.prevent_default();
+        }
+    });
+    document.add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())?;
+    listener.forget();
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! Breakpoints of the responsive layout, chosen from the size of the frame.
+
+use ratzilla::ratatui::layout::Size;
+
+/// Narrowest frame showing the list beside the selected topic
+const WIDE_MIN_WIDTH: u16 = 120;
+
+/// Narrowest frame showing the list and the selected topic at once
+const MEDIUM_MIN_WIDTH: u16 = 60;
+
+/// Shortest frame showing the list and the selected topic at once
+const MEDIUM_MIN_HEIGHT: u16 = 20;
+
+/// How the menu is arranged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// One pane at a time, the list or the selected topic, e.g. phones
+    Narrow,
+    /// The list above the selected topic
+    #[default]
+    Medium,
+    /// The list beside the selected topic
+    Wide,
+}
+
+impl Breakpoint {
+    /// Pick the breakpoint of a frame of the given size.
+    #[must_use]
+    pub const fn from_size(size: Size) -> Self {
+        if size.width < MEDIUM_MIN_WIDTH || size.height < MEDIUM_MIN_HEIGHT {
+            Self::Narrow
+        } else if size.width < WIDE_MIN_WIDTH {
+            Self::Medium
+        } else {
+            Self::Wide
+        }
+    }
+}
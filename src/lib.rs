@@ -1,3 +1,22 @@
 pub mod backend;
+pub mod clipboard;
+pub mod deep_link;
 pub mod fps;
+pub mod headless;
+pub mod keymap;
+pub mod layout;
+pub mod markdown;
+pub mod models;
+pub mod motion;
+pub mod pointer;
+mod scenes;
+pub mod screen_reader;
+pub mod state;
+pub mod storage;
+pub mod theme;
 pub mod utils;
+
+const PROMPT: &str = "visitor@danielegiachetto.com:$ ~";
+
+/// Address of the website, shared links point to it when the page address is unknown
+const HOMEPAGE: &str = "https://danielegiachetto.com/";
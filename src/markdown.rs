@@ -0,0 +1,364 @@
+//! Render markdown descriptions as styled ratatui text.
+//!
+//! Only the `CommonMark` subset used by the `content/` files is styled: headings, ordered and
+//! unordered lists, emphasis, strikethrough, inline code, code blocks and links. Code blocks
+//! are kept verbatim, which is how the ASCII art is written. Anything else is shown as text.
+//!
+//! A code block wider than the available width is dropped. A block fenced as ```` ```compact ````
+//! is the alternative of the block right before it, it is only shown when that one is dropped.
+
+use crate::theme::Theme;
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use ratzilla::ratatui::style::{Modifier, Style};
+use ratzilla::ratatui::text::{Line, Span, Text};
+
+/// Convert a markdown document into text styled with `theme`, `max_width` columns wide at most.
+#[must_use]
+pub fn to_text(markdown: &str, max_width: usize, theme: &Theme) -> Text<'static> {
+    let mut renderer = Renderer {
+        max_width,
+        theme: *theme,
+        ..Renderer::default()
+    };
+    for event in Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH) {
+        renderer.handle(event);
+    }
+    renderer.finish()
+}
+
+/// Convert a markdown document into plain lines, for screen readers.
+///
+/// ASCII art means nothing once read aloud, only the compact alternatives of code blocks are kept.
+#[must_use]
+pub fn to_plain_lines(markdown: &str) -> Vec<String> {
+    let mut renderer = Renderer {
+        max_width: usize::MAX,
+        compact_only: true,
+        ..Renderer::default()
+    };
+    for event in Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH) {
+        renderer.handle(event);
+    }
+    renderer
+        .finish()
+        .lines
+        .iter()
+        .map(|line| {
+            line.spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect()
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct Renderer {
+    lines: Vec<Line<'static>>,
+    current: Vec<Span<'static>>,
+    styles: Vec<Style>,
+    /// Next number of every open list, `None` for unordered lists
+    lists: Vec<Option<u64>>,
+    /// Destination of the link being rendered, with the text rendered so far
+    link: Option<(String, String)>,
+    /// Index of the first line of the code block being rendered, and whether it is compact
+    code_block: Option<(usize, bool)>,
+    /// Whether the last code block was too wide, its compact alternative replaces it
+    dropped_code_block: bool,
+    max_width: usize,
+    /// Drop every code block but the compact ones, as if they were all too wide
+    compact_only: bool,
+    theme: Theme,
+    /// Set right after a list bullet, the item content goes on the same line
+    in_item_start: bool,
+}
+
+impl Renderer {
+    fn handle(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) if self.code_block.is_some() => self.code_block_text(&text),
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+                self.text(text.into_string());
+            }
+            Event::Code(code) => self.push(Span::styled(code.into_string(), self.theme.code)),
+            Event::SoftBreak => self.text(" ".to_string()),
+            Event::HardBreak => self.flush_line(),
+            Event::Rule => {
+                self.start_block();
+                self.push(Span::styled("───", self.theme.heading));
+                self.flush_line();
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph => self.start_block(),
+            Tag::Heading { .. } => {
+                self.start_block();
+                self.styles.push(self.theme.heading);
+            }
+            Tag::BlockQuote(_) | Tag::Emphasis => self
+                .styles
+                .push(self.style().add_modifier(Modifier::ITALIC)),
+            Tag::CodeBlock(kind) => {
+                let compact =
+                    matches!(kind, CodeBlockKind::Fenced(info) if info.as_ref() == "compact");
+                self.flush_line();
+                self.code_block = Some((self.lines.len(), compact));
+            }
+            Tag::List(start) => {
+                if self.lists.is_empty() {
+                    self.start_block();
+                } else {
+                    self.flush_line();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush_line();
+                let depth = self.lists.len().saturating_sub(1);
+                let bullet = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}{}. ", "  ".repeat(depth), *number - 1)
+                    }
+                    _ => format!("{}• ", "  ".repeat(depth)),
+                };
+                self.push(Span::styled(bullet, Style::new().fg(self.theme.text)));
+                self.in_item_start = true;
+            }
+            Tag::Strong => self.styles.push(self.style().add_modifier(Modifier::BOLD)),
+            Tag::Strikethrough => self
+                .styles
+                .push(self.style().add_modifier(Modifier::CROSSED_OUT)),
+            Tag::Link { dest_url, .. } => {
+                self.link = Some((dest_url.into_string(), String::new()));
+                self.styles.push(self.style().patch(self.theme.link));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::Item => self.flush_line(),
+            TagEnd::Heading(_) => {
+                self.styles.pop();
+                self.flush_line();
+            }
+            TagEnd::CodeBlock => {
+                if !self.current.is_empty() {
+                    self.flush_line();
+                }
+                if let Some((start, compact)) = self.code_block.take() {
+                    self.end_code_block(start, compact);
+                }
+            }
+            TagEnd::List(_) => {
+                self.flush_line();
+                self.lists.pop();
+            }
+            TagEnd::BlockQuote(_) | TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.styles.pop();
+            }
+            TagEnd::Link => {
+                self.styles.pop();
+                if let Some((url, text)) = self.link.take() {
+                    // Show where the link goes, unless the text already is the destination.
+                    // A link without text is only its destination.
+                    let separator = if text.is_empty() { "" } else { " " };
+                    if !is_destination(&text, &url) {
+                        self.push(Span::styled(
+                            format!("{separator}<{url}>"),
+                            Style::new().fg(self.theme.link_url),
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: String) {
+        if let Some((_, link_text)) = &mut self.link {
+            link_text.push_str(&text);
+        }
+        let style = self.style();
+        self.push(Span::styled(text, style));
+    }
+
+    /// Code blocks are verbatim, their empty lines are kept
+    fn code_block_text(&mut self, text: &str) {
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                let spans = std::mem::take(&mut self.current);
+                self.lines.push(Line::from(spans));
+            }
+            if !line.is_empty() {
+                self.push(Span::styled(
+                    line.to_string(),
+                    Style::new().fg(self.theme.data),
+                ));
+            }
+        }
+    }
+
+    /// Keep the code block starting at `start` only if it fits, see the module documentation
+    fn end_code_block(&mut self, start: usize, compact: bool) {
+        let fits = (compact || !self.compact_only)
+            && self.lines[start..]
+                .iter()
+                .all(|line| line.width() <= self.max_width);
+        let show = fits && (!compact || self.dropped_code_block);
+        self.dropped_code_block = !compact && !fits;
+        if !show {
+            self.lines.truncate(start);
+        } else if start > 0 && self.lines[start - 1].width() > 0 {
+            // Separate the block from the previous one, as `start_block` does
+            self.lines.insert(start, Line::default());
+        }
+    }
+
+    fn style(&self) -> Style {
+        self.styles.last().copied().unwrap_or_default()
+    }
+
+    fn push(&mut self, span: Span<'static>) {
+        self.current.push(span);
+    }
+
+    /// Separate a new block from the previous one with an empty line
+    fn start_block(&mut self) {
+        if self.in_item_start {
+            self.in_item_start = false;
+            return;
+        }
+        self.flush_line();
+        if self.lines.last().is_some_and(|line| line.width() > 0) {
+            self.lines.push(Line::default());
+        }
+    }
+
+    fn flush_line(&mut self) {
+        self.in_item_start = false;
+        if self.current.is_empty() {
+            return;
+        }
+        self.lines
+            .push(Line::from(std::mem::take(&mut self.current)));
+    }
+
+    fn finish(mut self) -> Text<'static> {
+        self.flush_line();
+        while self.lines.last().is_some_and(|line| line.width() == 0) {
+            self.lines.pop();
+        }
+        Text::from(self.lines)
+    }
+}
+
+/// Whether the text of a link reads as its `url`, the scheme and a trailing `/` aside
+fn is_destination(text: &str, url: &str) -> bool {
+    fn bare(address: &str) -> &str {
+        let address = ["https://", "http://", "mailto:"]
+            .iter()
+            .find_map(|scheme| address.strip_prefix(scheme))
+            .unwrap_or(address);
+        address.strip_suffix('/').unwrap_or(address)
+    }
+    text == url || (!text.is_empty() && bare(text) == bare(url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Style of the span of `text` in the rendered `markdown`
+    fn style_of(markdown: &str, text: &str) -> Style {
+        to_text(markdown, 80, &Theme::default())
+            .lines
+            .iter()
+            .flat_map(|line| line.spans.iter())
+            .find(|span| span.content == text)
+            .map_or_else(|| panic!("no span {text:?}"), |span| span.style)
+    }
+
+    #[test]
+    fn headings() {
+        assert_eq!(to_plain_lines("# Title\n\nText"), ["Title", "", "Text"]);
+        assert_eq!(style_of("## Title", "Title"), Theme::default().heading);
+    }
+
+    #[test]
+    fn ordered_lists() {
+        assert_eq!(to_plain_lines("3. three\n4. four"), ["3. three", "4. four"]);
+    }
+
+    #[test]
+    fn nested_lists() {
+        assert_eq!(
+            to_plain_lines("- fruit\n  1. apple\n  2. pear\n- bread"),
+            ["• fruit", "  1. apple", "  2. pear", "• bread"]
+        );
+    }
+
+    #[test]
+    fn strikethrough() {
+        assert_eq!(to_plain_lines("~~gone~~ here"), ["gone here"]);
+        assert!(
+            style_of("~~gone~~", "gone")
+                .add_modifier
+                .contains(Modifier::CROSSED_OUT)
+        );
+    }
+
+    #[test]
+    fn inline_code() {
+        assert_eq!(
+            to_plain_lines("Run `cargo test` now"),
+            ["Run cargo test now"]
+        );
+        assert_eq!(style_of("`cargo`", "cargo"), Theme::default().code);
+    }
+
+    #[test]
+    fn links() {
+        assert_eq!(
+            to_plain_lines("[docs](https://docs.rs)"),
+            ["docs <https://docs.rs>"]
+        );
+        // The text already says where the link goes
+        assert_eq!(to_plain_lines("[docs.rs](https://docs.rs)"), ["docs.rs"]);
+        assert_eq!(to_plain_lines("<https://docs.rs>"), ["https://docs.rs"]);
+        assert_eq!(to_plain_lines("[docs.rs/](http://docs.rs)"), ["docs.rs/"]);
+        assert_eq!(
+            to_plain_lines("[me@example.com](mailto:me@example.com)"),
+            ["me@example.com"]
+        );
+    }
+
+    #[test]
+    fn links_ending_with_their_text() {
+        assert_eq!(
+            to_plain_lines("[rs](https://docs.rs)"),
+            ["rs <https://docs.rs>"]
+        );
+        assert_eq!(
+            to_plain_lines("[Profile](https://example.com/Profile)"),
+            ["Profile <https://example.com/Profile>"]
+        );
+    }
+
+    #[test]
+    fn links_without_text() {
+        assert_eq!(to_plain_lines("[](https://docs.rs)"), ["<https://docs.rs>"]);
+        assert_eq!(
+            to_plain_lines("See [](https://docs.rs)"),
+            ["See <https://docs.rs>"]
+        );
+    }
+}
@@ -1,22 +1,71 @@
+use crate::PROMPT;
+use crate::keymap::{Action, Keymap};
+use crate::layout::Breakpoint;
+use crate::markdown;
+use crate::models::quote::{Quote, QuotePicker};
 use crate::models::status::Status;
-use crate::models::topic::Topic;
-use crate::{NORMAL_BG, SELECTED_STYLE, TEXT_DATA_COLOR, TODO_HEADER_STYLE};
+use crate::models::toast::Toaster;
+use crate::models::topic::{Link, Topic};
+use crate::pointer::{MouseEvent, MouseEventKind};
+use crate::theme::Theme;
+use crate::utils::open_link;
 use ratzilla::ratatui::buffer::Buffer;
-use ratzilla::ratatui::layout::{Constraint, Layout, Rect};
-use ratzilla::ratatui::prelude::{Line, StatefulWidget, Style, Stylize, Widget};
+use ratzilla::ratatui::layout::{Constraint, Layout, Margin, Position, Rect};
+use ratzilla::ratatui::prelude::{Line, Span, StatefulWidget, Style, Stylize, Text, Widget};
+use ratzilla::ratatui::style::Modifier;
 use ratzilla::ratatui::symbols;
 use ratzilla::ratatui::widgets::{
-    Block, Borders, HighlightSpacing, List, ListItem, ListState, Padding, Paragraph, Wrap,
+    Block, Borders, HighlightSpacing, List, ListItem, ListState, Padding, Paragraph, Scrollbar,
+    ScrollbarOrientation, ScrollbarState, Wrap,
 };
-use ratzilla::utils::open_url;
-use strum::IntoEnumIterator;
 
 #[derive(Default)]
 pub struct App {
-    should_exit: bool,
     todo_list: BulletPoints,
+    /// Where the list was last rendered, to find the item under the mouse
+    list_area: Rect,
+    /// URLs shown in the detail pane and its links row at the last render, with the cells
+    /// they cover
+    links: Vec<(Rect, String)>,
+    /// Breakpoint of the last render, narrow screens show one pane at a time
+    breakpoint: Breakpoint,
+    /// Whether the selected topic fills the screen, only on narrow screens
+    detail_open: bool,
+    /// Title row of the open detail pane, clicking it goes back to the list
+    back_area: Rect,
+    /// Markdown description of the selected topic at the last render
+    description: String,
+    /// Line of the description to scroll to at the next render
+    scroll_to: Option<String>,
+    /// Topic of the detail pane at the last render, a newly selected topic starts unscrolled
+    shown_topic: Option<Topic>,
+    /// Rows of the detail pane shown at the last render, a page
+    detail_page: u16,
+    /// Rows the detail pane could be scrolled past at the last render
+    detail_max_scroll: u16,
+    /// Where the detail pane was last rendered, the wheel scrolls it
+    detail_area: Rect,
+    /// Messages shown over the panes for a while, e.g. after a copy
+    toaster: Toaster,
+    /// Draws the quote of quote topics
+    quotes: QuotePicker,
 }
 
+/// When a selection draws a new quote for the quote topic
+#[derive(Clone, Copy)]
+enum Draw {
+    /// The visitor moved to the topic, from another one
+    OnMove,
+    /// The topic has no quote yet, the one shown is never replaced
+    IfNone,
+}
+
+/// Width of the list when it is beside the selected topic
+const WIDE_LIST_WIDTH: u16 = 30;
+
+/// Rows of the detail pane scrolled by a turn of the wheel
+const WHEEL_ROWS: i32 = 3;
+
 struct BulletPoints {
     items: Vec<BulletItem>,
     state: ListState,
@@ -26,6 +75,12 @@ struct BulletPoints {
 struct BulletItem {
     topic: Topic,
     status: Status,
+    /// Rows of the description scrolled past
+    scroll: u16,
+    /// Index of the link focused with Tab, in the links of the topic
+    link: Option<usize>,
+    /// Quote shown by a quote topic, drawn when the topic is selected
+    quote: Option<&'static Quote>,
 }
 
 impl FromIterator<(Status, Topic)> for BulletPoints {
@@ -41,7 +96,9 @@ impl FromIterator<(Status, Topic)> for BulletPoints {
 
 impl Default for BulletPoints {
     fn default() -> Self {
-        Topic::iter().map(|title| (Status::Todo, title)).collect()
+        Topic::registry()
+            .map(|title| (Status::Todo, title))
+            .collect()
     }
 }
 
@@ -50,8 +107,20 @@ impl BulletItem {
         Self {
             topic: item,
             status,
+            scroll: 0,
+            link: None,
+            quote: None,
         }
     }
+
+    fn to_list_item(&self, theme: &Theme) -> ListItem<'static> {
+        let status = self.status;
+        let style = Style::new().italic().fg(status.get_status_color(theme));
+        ListItem::new(Line::styled(
+            format!(" {} {}", status.get_status_char(), self.topic),
+            style,
+        ))
+    }
 }
 
 impl App {
@@ -68,60 +137,337 @@ impl App {
     }
 
     pub fn on_left(&mut self) {
-        self.select_none();
+        if self.detail_open {
+            self.detail_open = false;
+        } else {
+            self.select_none();
+        }
     }
 
+    /// Narrow screens open the selected topic, the others change its status.
     pub fn on_enter(&mut self) {
-        self.on_right();
+        if self.breakpoint == Breakpoint::Narrow && !self.detail_open {
+            self.detail_open = self.todo_list.state.selected().is_some();
+        } else {
+            self.on_right();
+        }
     }
 
+    /// Open the focused link of the selected topic, or its first link.
     pub fn open_link(&self) {
-        if let Some(index) = self.todo_list.state.selected() {
-            if let Some(bul_item) = self.todo_list.items.get(index) {
-                let url = bul_item.topic.get_link();
-                if !url.is_empty() {
-                    let _ = open_url(url.as_str(), true);
+        if let Some(item) = self.selected_item() {
+            if let Some(link) = item.topic.links().get(item.link.unwrap_or(0)) {
+                open_link(link.url);
+            }
+        }
+    }
+
+    /// Copy the focused link of the selected topic, or its whole description.
+    ///
+    /// A toast tells whether the copy worked once the browser has answered.
+    pub fn copy(&mut self) {
+        let Some(topic) = self.selected_topic() else {
+            self.toaster
+                .show("Select a topic to copy it".to_string(), false);
+            return;
+        };
+        let (what, text) = match self.focused_link() {
+            // Email addresses are copied without their scheme, ready to paste
+            Some(link) => (
+                format!("the {} link", link.label),
+                link.url
+                    .strip_prefix("mailto:")
+                    .unwrap_or(link.url)
+                    .to_string(),
+            ),
+            None => (
+                topic.to_string(),
+                markdown::to_plain_lines(&self.description).join("\n"),
+            ),
+        };
+        self.toaster.copy(what, &text);
+    }
+
+    /// Message of the toast shown over the panes, if any.
+    #[must_use]
+    pub fn toast(&self) -> Option<&str> {
+        self.toaster.message()
+    }
+
+    /// Link of the selected topic focused with Tab, if any.
+    #[must_use]
+    pub fn focused_link(&self) -> Option<Link> {
+        let item = self.selected_item()?;
+        item.topic.links().get(item.link?).copied()
+    }
+
+    /// Focus the next link of the selected topic, the first one after the last.
+    pub fn focus_next_link(&mut self) {
+        self.cycle_link(true);
+    }
+
+    /// Focus the previous link of the selected topic, the last one before the first.
+    pub fn focus_previous_link(&mut self) {
+        self.cycle_link(false);
+    }
+
+    fn cycle_link(&mut self, forward: bool) {
+        let Some(item) = self.selected_item_mut() else {
+            return;
+        };
+        let count = item.topic.links().len();
+        if count == 0 {
+            return;
+        }
+        let index = match (item.link, forward) {
+            (Some(index), true) => (index + 1) % count,
+            (Some(index), false) => (index + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        };
+        item.link = Some(index);
+        // Bring the focused URL into sight when the description shows it off screen
+        let url = item.topic.links()[index].url;
+        if !self.links.iter().any(|(_, shown)| shown == url) {
+            self.scroll_to = Some(url.to_string());
+        }
+    }
+
+    /// Topic of the selected list item, if any.
+    #[must_use]
+    pub fn selected_topic(&self) -> Option<Topic> {
+        self.selected_item().map(|item| item.topic)
+    }
+
+    /// Select the list item of `topic`, or nothing.
+    ///
+    /// This is not a move of the visitor, e.g. a restored visit or a link: the quote shown
+    /// stays, a quote topic only gets one if it has none yet.
+    pub fn select_topic(&mut self, topic: Option<Topic>) {
+        let index = topic.and_then(|topic| {
+            self.todo_list
+                .items
+                .iter()
+                .position(|item| item.topic == topic)
+        });
+        self.select_with(Draw::IfNone, |state| state.select(index));
+    }
+
+    /// Draw quotes with `picker` from now on, starting with a new one.
+    pub fn set_quotes(&mut self, picker: QuotePicker) {
+        self.quotes = picker;
+        let quote = self.draw_quote();
+        self.restore_quote(quote);
+    }
+
+    /// Quote shown by the selected quote topic, or else by the first one.
+    #[must_use]
+    pub fn quote(&self) -> Option<&'static Quote> {
+        self.selected_item()
+            .and_then(|item| item.quote)
+            .or_else(|| self.todo_list.items.iter().find_map(|item| item.quote))
+    }
+
+    /// Show `quote` in every quote topic, e.g. after a reload.
+    pub fn restore_quote(&mut self, quote: Option<&'static Quote>) {
+        for item in &mut self.todo_list.items {
+            if item.topic.is_quote() {
+                item.quote = quote;
+            }
+        }
+    }
+
+    /// Next quote of the picker
+    fn draw_quote(&mut self) -> Option<&'static Quote> {
+        self.quotes
+            .next(Quote::all().len())
+            .map(|index| &Quote::all()[index])
+    }
+
+    /// Change the selection with `change`.
+    ///
+    /// Quotes are only drawn here, as `draw` tells: when the visitor selects a quote topic
+    /// still to read it shows a new quote, the quote stays the same for as long as it is
+    /// selected.
+    fn select_with(&mut self, draw: Draw, change: impl FnOnce(&mut ListState)) {
+        let previous = self.selected_item().map(|item| item.topic);
+        change(&mut self.todo_list.state);
+        // Moving past the last item selects it, as rendering the list would
+        if let Some(last) = self.todo_list.items.len().checked_sub(1) {
+            if self
+                .todo_list
+                .state
+                .selected()
+                .is_some_and(|index| index > last)
+            {
+                self.todo_list.state.select(Some(last));
+            }
+        }
+        let draw = self.selected_item().is_some_and(|item| {
+            item.topic.is_quote()
+                && match draw {
+                    Draw::OnMove => Some(item.topic) != previous && item.status == Status::Todo,
+                    Draw::IfNone => item.quote.is_none(),
+                }
+        });
+        if draw {
+            let quote = self.draw_quote();
+            if let Some(item) = self.selected_item_mut() {
+                item.quote = quote;
+            }
+        }
+    }
+
+    /// Scroll the detail pane to the first line containing `line` at the next render.
+    ///
+    /// Narrow screens open the detail pane to show it.
+    pub fn scroll_detail_to(&mut self, line: String) {
+        self.detail_open = true;
+        self.scroll_to = Some(line);
+    }
+
+    /// Scroll the description of the selected topic by `rows`, up when negative.
+    pub fn scroll_detail(&mut self, rows: i32) {
+        let max_scroll = self.detail_max_scroll;
+        if let Some(item) = self.selected_item_mut() {
+            let scroll = i32::from(item.scroll).saturating_add(rows);
+            item.scroll = u16::try_from(scroll.max(0))
+                .unwrap_or(u16::MAX)
+                .min(max_scroll);
+        }
+    }
+
+    /// Scroll the description of the selected topic by `pages`, up when negative.
+    ///
+    /// A page keeps one row of the previous one in sight.
+    pub fn page_detail(&mut self, pages: i32) {
+        let page = i32::from(self.detail_page.saturating_sub(1).max(1));
+        self.scroll_detail(pages.saturating_mul(page));
+    }
+
+    /// Selected list item, clamped to the last one in case the selection went past it
+    fn selected_item(&self) -> Option<&BulletItem> {
+        let last = self.todo_list.items.len().checked_sub(1)?;
+        let index = self.todo_list.state.selected()?.min(last);
+        self.todo_list.items.get(index)
+    }
+
+    fn selected_item_mut(&mut self) -> Option<&mut BulletItem> {
+        let last = self.todo_list.items.len().checked_sub(1)?;
+        let index = self.todo_list.state.selected()?.min(last);
+        self.todo_list.items.get_mut(index)
+    }
+
+    /// Click selects a topic, double click changes its status, the wheel moves the
+    /// selection, or scrolls the detail pane under it, and a click on a URL of the detail
+    /// pane opens it.
+    pub fn on_mouse_event(&mut self, event: &MouseEvent) {
+        let on_detail = self.detail_area.contains(event.position);
+        match event.kind {
+            MouseEventKind::ScrollUp if on_detail => self.scroll_detail(-WHEEL_ROWS),
+            MouseEventKind::ScrollDown if on_detail => self.scroll_detail(WHEEL_ROWS),
+            MouseEventKind::ScrollUp => self.on_up(),
+            MouseEventKind::ScrollDown => self.on_down(),
+            MouseEventKind::Click | MouseEventKind::DoubleClick => {
+                if let Some(index) = self.item_at(event.position) {
+                    self.select_with(Draw::OnMove, |state| state.select(Some(index)));
+                    if event.kind == MouseEventKind::DoubleClick {
+                        self.toggle_status();
+                    } else if self.breakpoint == Breakpoint::Narrow {
+                        self.detail_open = true;
+                    }
+                } else if self.back_area.contains(event.position) {
+                    self.detail_open = false;
+                } else if event.kind == MouseEventKind::Click {
+                    if let Some((_, url)) = self
+                        .links
+                        .iter()
+                        .find(|(area, _)| area.contains(event.position))
+                    {
+                        open_link(url);
+                    }
                 }
             }
         }
     }
-    pub fn on_key(&mut self, c: char) {
-        match c {
-            'q' => self.should_exit = true,
-            'w' | '↑' => self.on_up(),
-            'a' | '←' => self.on_left(),
-            's' | '↓' => self.on_down(),
-            'd' => self.on_right(),
-            'h' => self.select_first(),
-            'e' => self.select_last(),
 
-            _ => {}
+    /// Index of the list item rendered at `position`, if any
+    fn item_at(&self, position: Position) -> Option<usize> {
+        // The first row of the list area is its title
+        let items = Rect {
+            y: self.list_area.y + 1,
+            height: self.list_area.height.saturating_sub(1),
+            ..self.list_area
+        };
+        if !items.contains(position) {
+            return None;
+        }
+        let index = self.todo_list.state.offset() + usize::from(position.y - items.y);
+        (index < self.todo_list.items.len()).then_some(index)
+    }
+
+    /// Topics of the completed list items, in list order.
+    #[must_use]
+    pub fn completed_topics(&self) -> Vec<Topic> {
+        self.todo_list
+            .items
+            .iter()
+            .filter(|item| item.status == Status::Completed)
+            .map(|item| item.topic)
+            .collect()
+    }
+
+    /// Every topic with its status, in list order.
+    #[must_use]
+    pub fn topics(&self) -> Vec<(Topic, Status)> {
+        self.todo_list
+            .items
+            .iter()
+            .map(|item| (item.topic, item.status))
+            .collect()
+    }
+
+    /// Markdown description of the selected topic, as shown at the last render.
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Mark the list items of `topics` as completed, every other one as todo.
+    pub fn set_completed_topics(&mut self, topics: &[Topic]) {
+        for item in &mut self.todo_list.items {
+            item.status = if topics.contains(&item.topic) {
+                Status::Completed
+            } else {
+                Status::Todo
+            };
         }
     }
 
     fn select_none(&mut self) {
+        self.detail_open = false;
         self.todo_list.state.select(None);
     }
 
     fn select_next(&mut self) {
-        self.todo_list.state.select_next();
+        self.select_with(Draw::OnMove, ListState::select_next);
     }
     fn select_previous(&mut self) {
-        self.todo_list.state.select_previous();
+        self.select_with(Draw::OnMove, ListState::select_previous);
     }
 
-    fn select_first(&mut self) {
-        self.todo_list.state.select_first();
+    pub fn select_first(&mut self) {
+        self.select_with(Draw::OnMove, ListState::select_first);
     }
 
-    fn select_last(&mut self) {
-        self.todo_list.state.select_last();
+    pub fn select_last(&mut self) {
+        self.select_with(Draw::OnMove, ListState::select_last);
     }
 
     /// Changes the status of the selected list item
     fn toggle_status(&mut self) {
-        if let Some(i) = self.todo_list.state.selected() {
-            self.todo_list.items[i].status = match self.todo_list.items[i].status {
+        if let Some(item) = self.selected_item_mut() {
+            item.status = match item.status {
                 Status::Completed => Status::Todo,
                 Status::Todo => Status::Completed,
             }
@@ -129,49 +475,115 @@ impl App {
     }
 }
 
-impl Widget for &mut App {
-    fn render(self, area: Rect, buf: &mut Buffer) {
+/// Rendering logic for the app
+impl App {
+    /// Render the header, the list, the selected topic and the help of `keymap` with `theme`.
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer, theme: &Theme, keymap: &Keymap) {
+        self.breakpoint = Breakpoint::from_size(area.as_size());
+        self.detail_open &=
+            self.breakpoint == Breakpoint::Narrow && self.todo_list.state.selected().is_some();
+
+        // Computed even when the detail pane is hidden, screen readers still read it
+        self.description = self
+            .selected_item()
+            .map(|item| item.topic.get_description(item.quote))
+            .unwrap_or_default();
+
+        let footer = self.footer(theme, keymap);
+        let footer_height = u16::try_from(footer.line_count(area.width)).unwrap_or(u16::MAX);
+        let header_height = if self.breakpoint == Breakpoint::Narrow {
+            1
+        } else {
+            2
+        };
         let [header_area, main_area, footer_area] = Layout::vertical([
-            Constraint::Length(2),
+            Constraint::Length(header_height),
             Constraint::Fill(1),
-            Constraint::Length(1),
+            Constraint::Length(footer_height),
         ])
         .areas(area);
 
-        let [list_area, item_area] =
-            Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(main_area);
+        let [list_area, item_area] = match self.breakpoint {
+            Breakpoint::Wide => {
+                Layout::horizontal([Constraint::Length(WIDE_LIST_WIDTH), Constraint::Fill(1)])
+                    .areas(main_area)
+            }
+            Breakpoint::Medium => {
+                Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(main_area)
+            }
+            Breakpoint::Narrow if self.detail_open => [Rect::default(), main_area],
+            Breakpoint::Narrow => [main_area, Rect::default()],
+        };
 
-        App::render_header(header_area, buf);
-        App::render_footer(footer_area, buf);
-        self.render_list(list_area, buf);
-        self.render_selected_item(item_area, buf);
+        Self::render_header(header_area, buf, theme);
+        footer.render(footer_area, buf);
+        // Narrow screens leave one of the panes empty
+        if !list_area.is_empty() {
+            self.render_list(list_area, buf, theme);
+        }
+        self.links.clear();
+        if !item_area.is_empty() {
+            self.render_selected_item(item_area, buf, theme, keymap);
+        }
+        self.list_area = list_area;
+        self.detail_area = item_area;
+        self.back_area = if self.detail_open {
+            Rect {
+                height: 1,
+                ..item_area
+            }
+        } else {
+            Rect::default()
+        };
+
+        self.toaster.render(main_area, buf, theme);
     }
-}
 
-/// Rendering logic for the app
-impl App {
-    fn render_header(area: Rect, buf: &mut Buffer) {
+    fn render_header(area: Rect, buf: &mut Buffer, theme: &Theme) {
         Paragraph::new("Daniele's public data")
-            .light_magenta()
+            .fg(theme.title)
             .bold()
             .centered()
             .render(area, buf);
     }
 
-    fn render_footer(area: Rect, buf: &mut Buffer) {
-        Paragraph::new("Use ↓↑ or ws to move, ← or a to unselect, → or d to change status, h/e to go top/bottom, CTRL + Enter to open link.")
-            .light_magenta()
+    /// Help of the keys available in the shown panes, wrapped on small screens
+    fn footer(&self, theme: &Theme, keymap: &Keymap) -> Paragraph<'static> {
+        let help = match self.breakpoint {
+            Breakpoint::Narrow if self.detail_open => keymap.hint(&[
+                (&[Action::Back], "go back"),
+                (&[Action::ToggleStatus], "change status"),
+                (&[Action::OpenLink], "open link"),
+            ]),
+            Breakpoint::Narrow => keymap.hint(&[
+                (&[Action::Down, Action::Up], "move"),
+                (&[Action::Read], "read"),
+                (&[Action::ToggleStatus], "change status"),
+                (&[Action::Help], "list every key"),
+            ]),
+            Breakpoint::Medium | Breakpoint::Wide => keymap.hint(&[
+                (&[Action::Down, Action::Up], "move"),
+                (&[Action::Back], "unselect"),
+                (&[Action::ToggleStatus], "change status"),
+                (&[Action::First, Action::Last], "go top/bottom"),
+                (&[Action::OpenLink], "open link"),
+                (&[Action::Shell], "open the shell"),
+                (&[Action::Help], "list every key"),
+            ]),
+        };
+        Paragraph::new(format!("{help}."))
+            .fg(theme.title)
             .centered()
-            .render(area, buf);
+            .wrap(Wrap { trim: true })
     }
 
-    fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
+    fn render_list(&mut self, area: Rect, buf: &mut Buffer, theme: &Theme) {
         let block = Block::new()
             .title(Line::raw("Topics").centered())
             .borders(Borders::TOP)
             .border_set(symbols::border::EMPTY)
-            .border_style(TODO_HEADER_STYLE)
-            .bg(NORMAL_BG);
+            .border_style(theme.header)
+            .bg(theme.panel);
 
         // Iterate through all elements in the `items` and stylize them.
         let items: Vec<ListItem> = self
@@ -181,14 +593,14 @@ impl App {
             //.enumerate()
             .map(|todo_item| {
                 //let color = alternate_colors(i);
-                ListItem::from(todo_item) //.bg(color)
+                todo_item.to_list_item(theme) //.bg(color)
             })
             .collect();
 
         // Create a List from all list items and highlight the currently selected one
         let list = List::new(items)
             .block(block)
-            .highlight_style(SELECTED_STYLE)
+            .highlight_style(theme.selected)
             .highlight_symbol(">")
             .highlight_spacing(HighlightSpacing::Always);
 
@@ -197,44 +609,220 @@ impl App {
         StatefulWidget::render(list, area, buf, &mut self.todo_list.state);
     }
 
-    fn render_selected_item(&self, area: Rect, buf: &mut Buffer) {
+    fn render_selected_item(
+        &mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        theme: &Theme,
+        keymap: &Keymap,
+    ) {
+        // The links of the topic get their own row under the description
+        let links = self.selected_topic().map_or(&[][..], |topic| topic.links());
+        let [area, links_area] = if links.is_empty() {
+            [area, Rect::default()]
+        } else {
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(area)
+        };
+
         // We get the info depending on the item's state.
-        let info = self.todo_list.state.selected().map_or_else(
-            || "Nothing selected...".to_string(),
-            |i| {
-                let item = &self.todo_list.items[i];
-                let topic = item.topic.clone();
-                let descr = topic.get_description(item.status);
+        let info = self.selected_item().map_or_else(
+            || Text::raw("Nothing selected..."),
+            |item| {
+                let topic = item.topic;
+                // The block pads the description with a column on each side
+                let width = usize::from(area.width.saturating_sub(2));
+                let descr = markdown::to_text(&self.description, width, theme);
                 let command = topic.to_string().to_ascii_lowercase();
-                format!("visitor@danielegiachetto.com:$ ~ {command}:\n{descr}")
+                let mut info = Text::raw(format!("{PROMPT} {command}:"));
+                info.extend(descr);
+                info
             },
         );
 
         // We show the list item's info under the list in this paragraph
+        let title = if self.detail_open {
+            Line::raw("← Topics").left_aligned()
+        } else {
+            Line::raw("Terminal").centered()
+        };
         let block = Block::new()
-            .title(Line::raw("Terminal").centered())
+            .title(title)
             .borders(Borders::TOP)
             .border_set(symbols::border::EMPTY)
-            .border_style(TODO_HEADER_STYLE)
-            .bg(NORMAL_BG)
+            .border_style(theme.header)
+            .bg(theme.panel)
             .padding(Padding::horizontal(1));
 
+        // The block pads the description with a column on each side, below its title
+        let width = area.width.saturating_sub(2);
+        let height = area.height.saturating_sub(1);
+        let (scroll, page, max_scroll) = self.scroll_detail_rows(&info, width, height);
+        let scrollable = page < height;
+        let block = if scrollable {
+            let more = if scroll < max_scroll {
+                "▼ more below"
+            } else {
+                ""
+            };
+            block.title_bottom(Line::styled(more, theme.title).right_aligned())
+        } else {
+            block
+        };
+
         // We can now render the item info
         Paragraph::new(info)
             .block(block)
-            .fg(TEXT_DATA_COLOR)
+            .fg(theme.data)
             .wrap(Wrap { trim: false })
+            .scroll((scroll, 0))
             .render(area, buf);
+        if scrollable {
+            // The scrollbar takes the right padding column
+            let bar_area = Rect::new(area.right().saturating_sub(1), area.y + 1, 1, page);
+            let mut bar_state = ScrollbarState::new(usize::from(max_scroll))
+                .viewport_content_length(usize::from(page))
+                .position(usize::from(scroll));
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .thumb_style(theme.title)
+                .track_style(theme.completed)
+                .render(bar_area, buf, &mut bar_state);
+        }
+
+        let focused = self.focused_link();
+        self.links = find_links(area, buf);
+        for (link_area, url) in &self.links {
+            if focused.is_some_and(|link| link.url == url) {
+                buf.set_style(*link_area, focused_style(theme));
+            }
+        }
+        if !links_area.is_empty() {
+            let hint = keymap.hint(&[(&[Action::NextLink], "focus")]);
+            let shown = render_links(links, focused, &hint, links_area, buf, theme);
+            self.links.extend(shown);
+        }
+    }
+
+    /// Clamp the scroll of the selected topic to `info` shown `width` x `height`.
+    ///
+    /// Returns the rows scrolled past, the rows shown and the largest scroll. The scroll starts
+    /// over on a newly selected topic, or on the line asked by [`App::scroll_detail_to`].
+    fn scroll_detail_rows(&mut self, info: &Text, width: u16, height: u16) -> (u16, u16, u16) {
+        let selected = self.selected_topic();
+        let newly_selected = selected != self.shown_topic;
+        self.shown_topic = selected;
+        let wanted = self.scroll_to.take();
+        let rows_of = |lines: &[Line]| {
+            let rows = Paragraph::new(Text::from(lines.to_vec()))
+                .wrap(Wrap { trim: false })
+                .line_count(width);
+            u16::try_from(rows).unwrap_or(u16::MAX)
+        };
+        let rows = rows_of(&info.lines);
+        // A scrollable description leaves its last row to the "more below" indicator
+        let page = if rows > height {
+            height.saturating_sub(1)
+        } else {
+            height
+        };
+        let max_scroll = rows.saturating_sub(page);
+        self.detail_page = page;
+        self.detail_max_scroll = max_scroll;
+        let Some(item) = self.selected_item_mut() else {
+            return (0, page, max_scroll);
+        };
+        if let Some(wanted) = wanted {
+            // The rows wrapped before the wanted line are the ones to scroll past
+            let index = info
+                .lines
+                .iter()
+                .position(|line| line.to_string().contains(wanted.as_str()));
+            if let Some(index) = index {
+                item.scroll = rows_of(&info.lines[..index]);
+            }
+        } else if newly_selected {
+            item.scroll = 0;
+        }
+        item.scroll = item.scroll.min(max_scroll);
+        (item.scroll, page, max_scroll)
     }
 }
 
-impl From<&BulletItem> for ListItem<'_> {
-    fn from(value: &BulletItem) -> Self {
-        let status = value.status;
-        let style = Style::new().italic().fg(status.get_status_color());
-        ListItem::new(Line::styled(
-            format!(" {} {}", status.get_status_char(), value.topic),
-            style,
-        ))
+/// Style of the focused link, wherever it is shown
+const fn focused_style(theme: &Theme) -> Style {
+    theme.link.add_modifier(Modifier::REVERSED)
+}
+
+/// Render `links` on the single row of `area` after `hint`, `focused` highlighted.
+///
+/// Returns the URL of every link with the cells of its label.
+fn render_links(
+    links: &[Link],
+    focused: Option<Link>,
+    hint: &str,
+    area: Rect,
+    buf: &mut Buffer,
+    theme: &Theme,
+) -> Vec<(Rect, String)> {
+    Block::new().bg(theme.panel).render(area, buf);
+    // The row is padded like the description above it
+    let area = area.inner(Margin::new(1, 0));
+    let hint = format!("{hint}:");
+    let mut x = area.x + u16::try_from(hint.chars().count()).unwrap_or(u16::MAX);
+    let mut spans = vec![Span::styled(hint, theme.title)];
+    let mut shown = Vec::new();
+    for link in links {
+        let label = format!("{} {}", link.kind.symbol(), link.label);
+        let style = if focused == Some(*link) {
+            focused_style(theme)
+        } else {
+            theme.link
+        };
+        let width = u16::try_from(Line::raw(label.as_str()).width()).unwrap_or(u16::MAX);
+        x = x.saturating_add(1);
+        shown.push((
+            Rect::new(x, area.y, width, 1).intersection(area),
+            link.url.to_string(),
+        ));
+        x = x.saturating_add(width);
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(label, style));
+    }
+    Line::from(spans).render(area, buf);
+    shown.retain(|(cells, _)| !cells.is_empty());
+    shown
+}
+
+/// URLs written in `area`, with the cells they cover
+fn find_links(area: Rect, buf: &Buffer) -> Vec<(Rect, String)> {
+    let mut links = Vec::new();
+    for y in area.top()..area.bottom() {
+        // Text of the row, with the column each character comes from
+        let row: Vec<(u16, char)> = (area.left()..area.right())
+            .filter_map(|x| buf.cell((x, y)).map(|cell| (x, cell.symbol())))
+            .flat_map(|(x, symbol)| symbol.chars().map(move |c| (x, c)))
+            .collect();
+        let chars: Vec<char> = row.iter().map(|(_, c)| *c).collect();
+        let mut start = 0;
+        while start < chars.len() {
+            let rest: String = chars[start..chars.len().min(start + 8)].iter().collect();
+            if !["https://", "http://", "mailto:"]
+                .iter()
+                .any(|scheme| rest.starts_with(scheme))
+            {
+                start += 1;
+                continue;
+            }
+            let end = chars[start..]
+                .iter()
+                .position(|c| c.is_whitespace() || matches!(c, '>' | ')' | '|'))
+                .map_or(chars.len(), |len| start + len);
+            let url = chars[start..end].iter().collect();
+            let x = row[start].0;
+            links.push((Rect::new(x, y, row[end - 1].0 + 1 - x, 1), url));
+            start = end;
+        }
     }
+    links
 }
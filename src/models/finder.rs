@@ -0,0 +1,255 @@
+//! Fuzzy finder over the topic names and the text of their descriptions.
+//!
+//! Every topic name and every line of every description is a candidate, the characters of the
+//! query must appear in it in order. Candidates are ranked by how closely the characters follow
+//! each other and whether they start words, topic names first on a tie.
+
+use crate::markdown;
+use crate::models::quote::Quote;
+use crate::models::topic::Topic;
+use crate::theme::Theme;
+use ratzilla::event::{KeyCode, KeyEvent};
+use ratzilla::ratatui::buffer::Buffer;
+use ratzilla::ratatui::layout::{Constraint, Layout, Rect};
+use ratzilla::ratatui::prelude::{Line, Span, StatefulWidget, Style, Stylize, Widget};
+use ratzilla::ratatui::style::Modifier;
+use ratzilla::ratatui::widgets::{Block, Clear, HighlightSpacing, List, ListItem, ListState};
+
+/// Results kept for a query, the best ones first
+const MAX_RESULTS: usize = 50;
+
+/// Extra score of a topic name over a description line
+const NAME_BONUS: i32 = 10;
+
+/// Characters of a matched line shown before the first matched character
+const SNIPPET_CONTEXT: usize = 12;
+
+/// A candidate matching the query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub topic: Topic,
+    /// Line of the description that matched, `None` when the topic name did
+    pub line: Option<String>,
+    /// Indices of the matched characters, in the line or the topic name
+    pub positions: Vec<usize>,
+    pub score: i32,
+}
+
+/// Match `pattern` against `text`, case insensitive.
+///
+/// Returns the score and the indices of the matched characters, or `None` when the characters
+/// of `pattern` do not all appear in `text` in order.
+#[must_use]
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<(i32, Vec<usize>)> {
+    let text: Vec<char> = text.chars().collect();
+    let mut positions = Vec::new();
+    let mut score = 0;
+    let mut start = 0;
+    for wanted in pattern.chars().filter(|c| !c.is_whitespace()) {
+        let offset = text[start..]
+            .iter()
+            .position(|c| c.to_lowercase().eq(wanted.to_lowercase()))?;
+        let index = start + offset;
+        score += 1;
+        if positions.last().is_some_and(|last| last + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += 3;
+        }
+        positions.push(index);
+        start = index + 1;
+    }
+    // Among equal matches, the earliest and most compact one wins
+    let spread = match (positions.first(), positions.last()) {
+        (Some(first), Some(last)) => first / 8 + (last - first) / 4,
+        _ => 0,
+    };
+    Some((score - i32::try_from(spread).unwrap_or(i32::MAX), positions))
+}
+
+/// Every topic name and description line matching `query`, the best ones first.
+///
+/// An empty query matches every topic name, in list order. Quote topics are searched for
+/// `quote`, the one they show.
+#[must_use]
+pub fn search(query: &str, quote: Option<&Quote>) -> Vec<Match> {
+    let mut matches = Vec::new();
+    for topic in Topic::registry() {
+        if let Some((score, positions)) = fuzzy_match(query, &topic.to_string()) {
+            matches.push(Match {
+                topic,
+                line: None,
+                positions,
+                score: score + NAME_BONUS,
+            });
+        }
+        if query.trim().is_empty() {
+            continue;
+        }
+        let description = topic.get_description(quote);
+        for line in markdown::to_plain_lines(&description) {
+            if let Some((score, positions)) = fuzzy_match(query, &line) {
+                matches.push(Match {
+                    topic,
+                    line: Some(line),
+                    positions,
+                    score,
+                });
+            }
+        }
+    }
+    // The sort is stable, ties keep the list order
+    matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+    matches.truncate(MAX_RESULTS);
+    matches
+}
+
+/// Popup where visitors type a query and pick one of the results.
+pub struct Finder {
+    query: String,
+    results: Vec<Match>,
+    state: ListState,
+    /// Quote shown by quote topics, searched in their place
+    quote: Option<&'static Quote>,
+}
+
+impl Finder {
+    /// Finder listing every topic, quote topics showing `quote`.
+    #[must_use]
+    pub fn new(quote: Option<&'static Quote>) -> Self {
+        let mut finder = Self {
+            query: String::new(),
+            results: Vec::new(),
+            state: ListState::default(),
+            quote,
+        };
+        finder.update();
+        finder
+    }
+
+    /// Edit the query or move through the results, Enter and Esc are left to the caller.
+    pub fn on_key_event(&mut self, event: &KeyEvent) {
+        match event.code {
+            KeyCode::Char('u') if event.ctrl => {
+                self.query.clear();
+                self.update();
+            }
+            KeyCode::Char('n') if event.ctrl => self.state.select_next(),
+            KeyCode::Char('p') if event.ctrl => self.state.select_previous(),
+            KeyCode::Char(c) if !event.ctrl && !event.alt => {
+                self.query.push(c);
+                self.update();
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.update();
+            }
+            KeyCode::Down | KeyCode::Tab => self.state.select_next(),
+            KeyCode::Up => self.state.select_previous(),
+            _ => {}
+        }
+    }
+
+    #[must_use]
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    #[must_use]
+    pub fn results(&self) -> &[Match] {
+        &self.results
+    }
+
+    /// Result highlighted in the list, if any.
+    #[must_use]
+    pub fn selected(&self) -> Option<&Match> {
+        let last = self.results.len().checked_sub(1)?;
+        self.results.get(self.state.selected()?.min(last))
+    }
+
+    fn update(&mut self) {
+        self.results = search(&self.query, self.quote);
+        self.state.select((!self.results.is_empty()).then_some(0));
+    }
+
+    /// Render the query and the results over `area` with `theme`.
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer, theme: &Theme) {
+        Clear.render(area, buf);
+        let block = Block::bordered()
+            .title(Line::raw(" Find ").centered())
+            .title_bottom(Line::raw(" ↓↑ to move, Enter to read, Esc to close ").centered())
+            .border_style(theme.header)
+            .bg(theme.panel);
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let [query_area, results_area] =
+            Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(inner);
+
+        Line::from(vec![
+            Span::styled("/ ", theme.title),
+            Span::styled(format!("{}▏", self.query), theme.text),
+        ])
+        .render(query_area, buf);
+
+        let width = usize::from(results_area.width.saturating_sub(2));
+        let items: Vec<ListItem> = self
+            .results
+            .iter()
+            .map(|m| ListItem::new(result_line(m, width, theme)))
+            .collect();
+        let list = if items.is_empty() {
+            List::new([ListItem::new(Line::raw("No match").fg(theme.completed))])
+        } else {
+            List::new(items)
+        };
+        let list = list
+            .highlight_style(theme.selected.remove_modifier(Modifier::CROSSED_OUT))
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+        StatefulWidget::render(list, results_area, buf, &mut self.state);
+    }
+}
+
+/// Topic name of a result, then the matched line with the matched characters highlighted
+fn result_line(m: &Match, mut width: usize, theme: &Theme) -> Line<'static> {
+    let name = m.topic.to_string();
+    let mut spans = Vec::new();
+    let (text, positions) = match &m.line {
+        Some(line) => {
+            let prefix = format!("{name}: ");
+            width = width.saturating_sub(prefix.chars().count());
+            spans.push(Span::styled(prefix, theme.title));
+            (line.clone(), m.positions.as_slice())
+        }
+        None => (name, m.positions.as_slice()),
+    };
+    let chars: Vec<char> = text.chars().collect();
+    // Long lines are cut so that the first matched character is shown
+    let first = positions.first().copied().unwrap_or(0);
+    let start = if first + SNIPPET_CONTEXT > width {
+        first.saturating_sub(SNIPPET_CONTEXT)
+    } else {
+        0
+    };
+    if start > 0 {
+        spans.push(Span::styled("…", theme.data));
+    }
+    let highlight = Style::new().fg(theme.accent).bold().underlined();
+    let plain = Style::new().fg(theme.data);
+    // Consecutive characters with the same style share a span
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (index, c) in chars.iter().enumerate().skip(start) {
+        let matched = positions.contains(&index);
+        if matched != run_matched && !run.is_empty() {
+            let style = if run_matched { highlight } else { plain };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_matched = matched;
+        run.push(*c);
+    }
+    let style = if run_matched { highlight } else { plain };
+    spans.push(Span::styled(run, style));
+    Line::from(spans)
+}
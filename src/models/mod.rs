@@ -1,3 +1,8 @@
 pub mod app;
+pub mod finder;
+pub mod quote;
+pub mod quote_book;
+pub mod shell;
 pub mod status;
+pub mod toast;
 pub mod topic;
@@ -0,0 +1,195 @@
+//! Quotes, and which one the quote topic shows next.
+//!
+//! Quotes are compiled in from `content/quotes.txt` by the build script. They are drawn by a
+//! [`QuotePicker`], only when the visitor asks for one, never while rendering. The picker is
+//! seeded from the `?seed=` URL parameter, so that a sequence of quotes can be reproduced, and
+//! its mode from the `?quotes=` one.
+
+use crate::utils::url_param;
+use std::fmt::Display;
+use web_time::{SystemTime, UNIX_EPOCH};
+
+include!(concat!(env!("OUT_DIR"), "/quotes.rs"));
+
+/// A quote of `content/quotes.txt`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Quote {
+    /// Slug of the author, numbered among the quotes of the same author
+    pub id: &'static str,
+    pub text: &'static str,
+    pub author: &'static str,
+    /// Work the quote comes from
+    pub source: Option<&'static str>,
+    pub year: Option<i32>,
+    pub tags: &'static [&'static str],
+}
+
+/// Quoted text then its author, e.g. `"Information is the resolution of uncertainty." - Claude
+/// Shannon`, followed by the source and year when known.
+impl Display for Quote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"{}\" - {}", self.text, self.author)?;
+        if let Some(source) = self.source {
+            write!(f, ", {source}")?;
+        }
+        if let Some(year) = self.year {
+            write!(f, " ({year})")?;
+        }
+        Ok(())
+    }
+}
+
+impl Quote {
+    /// Every quote, in file order.
+    #[must_use]
+    pub fn all() -> &'static [Self] {
+        QUOTES
+    }
+
+    #[must_use]
+    pub fn from_id(id: &str) -> Option<&'static Self> {
+        QUOTES
+            .iter()
+            .find(|quote| quote.id.eq_ignore_ascii_case(id))
+    }
+
+    /// Quote linked by the `?quote=` URL parameter, if any.
+    #[must_use]
+    pub fn from_browser() -> Option<&'static Self> {
+        url_param("quote").and_then(|id| Self::from_id(&id))
+    }
+
+    /// Whether the author or one of the tags contains `filter`, case insensitive.
+    #[must_use]
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter = filter.trim().to_lowercase();
+        self.author.to_lowercase().contains(&filter)
+            || self
+                .tags
+                .iter()
+                .any(|tag| tag.to_lowercase().contains(&filter))
+    }
+}
+
+/// Seconds in a UTC day
+const DAY_SECONDS: u64 = 24 * 60 * 60;
+
+/// How the next quote is drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuoteMode {
+    /// Any quote, the same one may come twice in a row
+    Random,
+    /// Every quote once in a random order, then again in another order
+    #[default]
+    Shuffle,
+    /// The same quote for everyone during a UTC day
+    Daily,
+}
+
+/// Every mode, to find one by name
+const MODES: [QuoteMode; 3] = [QuoteMode::Random, QuoteMode::Shuffle, QuoteMode::Daily];
+
+impl QuoteMode {
+    /// Name used by the `?quotes=` URL parameter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Random => "random",
+            Self::Shuffle => "shuffle",
+            Self::Daily => "daily",
+        }
+    }
+
+    /// Find a mode by name, case insensitive.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        MODES
+            .into_iter()
+            .find(|mode| mode.as_str().eq_ignore_ascii_case(name))
+    }
+}
+
+/// Draws the index of the next quote.
+#[derive(Debug, Clone)]
+pub struct QuotePicker {
+    mode: QuoteMode,
+    rng: fastrand::Rng,
+    /// Quotes left to draw in shuffle mode, the next one last
+    bag: Vec<usize>,
+    /// Quote drawn last, a new bag does not start with it
+    last: Option<usize>,
+}
+
+impl Default for QuotePicker {
+    fn default() -> Self {
+        Self::new(QuoteMode::default(), None)
+    }
+}
+
+impl QuotePicker {
+    /// Picker drawing in `mode`, from `seed` or from a random seed.
+    #[must_use]
+    pub fn new(mode: QuoteMode, seed: Option<u64>) -> Self {
+        Self {
+            mode,
+            rng: seed.map_or_else(fastrand::Rng::new, fastrand::Rng::with_seed),
+            bag: Vec::new(),
+            last: None,
+        }
+    }
+
+    /// Picker asked by the `?quotes=` and `?seed=` URL parameters, shuffled at random otherwise.
+    #[must_use]
+    pub fn from_browser() -> Self {
+        let mode = url_param("quotes")
+            .and_then(|name| QuoteMode::from_name(&name))
+            .unwrap_or_default();
+        let seed = url_param("seed").and_then(|seed| seed.parse().ok());
+        Self::new(mode, seed)
+    }
+
+    #[must_use]
+    pub const fn mode(&self) -> QuoteMode {
+        self.mode
+    }
+
+    /// Index of the next quote out of `count`, `None` without quotes.
+    pub fn next(&mut self, count: usize) -> Option<usize> {
+        if count == 0 {
+            return None;
+        }
+        let index = match self.mode {
+            QuoteMode::Random => self.rng.usize(..count),
+            QuoteMode::Shuffle => self.draw_from_bag(count),
+            QuoteMode::Daily => daily_index(today(), count),
+        };
+        self.last = Some(index);
+        Some(index)
+    }
+
+    fn draw_from_bag(&mut self, count: usize) -> usize {
+        // A bag filled for another number of quotes is stale
+        if self.bag.is_empty() || self.bag.iter().any(|index| *index >= count) {
+            self.bag = (0..count).collect();
+            self.rng.shuffle(&mut self.bag);
+            // The quote drawn last does not come again right away
+            if self.bag.last() == self.last.as_ref() {
+                self.bag.swap(0, count - 1);
+            }
+        }
+        self.bag.pop().unwrap_or_default()
+    }
+}
+
+/// Index of the quote of `day` out of `count`, days counted from the UNIX epoch in UTC.
+#[must_use]
+pub fn daily_index(day: u64, count: usize) -> usize {
+    fastrand::Rng::with_seed(day).usize(..count.max(1))
+}
+
+/// Days since the UNIX epoch, in UTC
+fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() / DAY_SECONDS)
+}
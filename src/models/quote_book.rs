@@ -0,0 +1,210 @@
+//! Scene browsing every quote, filtered by author or tag.
+
+use crate::HOMEPAGE;
+use crate::models::quote::Quote;
+use crate::models::toast::Toaster;
+use crate::theme::Theme;
+use ratzilla::event::{KeyCode, KeyEvent};
+use ratzilla::ratatui::buffer::Buffer;
+use ratzilla::ratatui::layout::{Constraint, Layout, Rect};
+use ratzilla::ratatui::prelude::{Line, Span, Stylize, Text, Widget};
+use ratzilla::ratatui::widgets::{Block, Padding, Paragraph, Wrap};
+use std::fmt::Write;
+
+/// Quotes shown one at a time, with a filter on their author and tags.
+#[derive(Default)]
+pub struct QuoteBook {
+    /// Index in [`Quote::all`] of the quote shown
+    current: usize,
+    filter: String,
+    /// Whether typed keys edit the filter
+    filtering: bool,
+    toaster: Toaster,
+    should_exit: bool,
+}
+
+impl QuoteBook {
+    /// Book opened on `quote`, or on the first quote.
+    #[must_use]
+    pub fn new(quote: Option<&Quote>) -> Self {
+        Self {
+            current: quote
+                .and_then(|quote| Quote::all().iter().position(|other| other == quote))
+                .unwrap_or(0),
+            ..Self::default()
+        }
+    }
+
+    /// Quote shown, `None` when no quote matches the filter.
+    #[must_use]
+    pub fn current(&self) -> Option<&'static Quote> {
+        let quote = Quote::all().get(self.current)?;
+        quote.matches(&self.filter).then_some(quote)
+    }
+
+    #[must_use]
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Message of the toast shown over the quote, if any.
+    #[must_use]
+    pub fn toast(&self) -> Option<&str> {
+        self.toaster.message()
+    }
+
+    /// Edit the filter, or browse and share the quotes.
+    pub fn on_key_event(&mut self, event: &KeyEvent) {
+        if self.filtering {
+            match event.code {
+                KeyCode::Enter | KeyCode::Esc => self.filtering = false,
+                KeyCode::Backspace => {
+                    self.filter.pop();
+                }
+                KeyCode::Char('u') if event.ctrl => self.filter.clear(),
+                KeyCode::Char(c) if !event.ctrl && !event.alt => self.filter.push(c),
+                _ => return,
+            }
+            // The shown quote moves to the first match when it no longer matches
+            if self.current().is_none() {
+                self.current = self.matching().first().copied().unwrap_or(self.current);
+            }
+            return;
+        }
+        match event.code {
+            KeyCode::Right | KeyCode::Down | KeyCode::Char('n' | ' ') => self.step(true),
+            KeyCode::Left | KeyCode::Up | KeyCode::Char('p') => self.step(false),
+            KeyCode::Char('/') => self.filtering = true,
+            KeyCode::Char('c') if event.ctrl => self.share(),
+            KeyCode::Char('s' | 'y') => self.share(),
+            KeyCode::Esc => self.should_exit = true,
+            _ => {}
+        }
+    }
+
+    /// Whether the visitor asked to leave the book, the request is cleared once read.
+    pub const fn take_exit_request(&mut self) -> bool {
+        std::mem::replace(&mut self.should_exit, false)
+    }
+
+    /// Indices of the quotes matching the filter
+    fn matching(&self) -> Vec<usize> {
+        Quote::all()
+            .iter()
+            .enumerate()
+            .filter(|(_, quote)| quote.matches(&self.filter))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Show the next matching quote, or the previous one, around the ends
+    fn step(&mut self, forward: bool) {
+        let matching = self.matching();
+        let Some(position) = matching.iter().position(|index| *index == self.current) else {
+            return;
+        };
+        let count = matching.len();
+        let position = if forward {
+            (position + 1) % count
+        } else {
+            (position + count - 1) % count
+        };
+        self.current = matching[position];
+    }
+
+    /// Copy the quote with a link opening the book on it.
+    fn share(&mut self) {
+        let Some(quote) = self.current() else {
+            self.toaster.show("No quote to share".to_string(), false);
+            return;
+        };
+        let text = format!("{quote}\n{}?quote={}", page_url(), quote.id);
+        self.toaster.copy("the quote".to_string(), &text);
+    }
+
+    /// Render the quote shown over `area` with `theme`.
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer, theme: &Theme) {
+        let matching = self.matching();
+        let title = matching
+            .iter()
+            .position(|index| *index == self.current)
+            .map_or_else(
+                || " Quotes ".to_string(),
+                |position| format!(" Quotes {}/{} ", position + 1, matching.len()),
+            );
+        let block = Block::bordered()
+            .title(Line::raw(title).centered())
+            .title_bottom(
+                Line::raw(
+                    " ←→ to browse, / to filter by author or tag, s to share, Esc to go back ",
+                )
+                .centered(),
+            )
+            .border_style(theme.header)
+            .bg(theme.panel)
+            .padding(Padding::uniform(1));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let [filter_area, quote_area] =
+            Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(inner);
+
+        if self.filtering || !self.filter.is_empty() {
+            let cursor = if self.filtering { "▏" } else { "" };
+            Line::from(vec![
+                Span::styled("/ ", theme.title),
+                Span::styled(format!("{}{cursor}", self.filter), theme.text),
+            ])
+            .render(filter_area, buf);
+        }
+
+        let text = self.current().map_or_else(
+            || Text::from(Line::raw("No quote by this author or with this tag").fg(theme.accent)),
+            |quote| quote_text(quote, theme),
+        );
+        Paragraph::new(text)
+            .centered()
+            .wrap(Wrap { trim: true })
+            .render(quote_area, buf);
+        self.toaster.render(inner, buf, theme);
+    }
+}
+
+/// Text, author and tags of `quote`, on separate paragraphs
+fn quote_text(quote: &Quote, theme: &Theme) -> Text<'static> {
+    let mut credit = format!("— {}", quote.author);
+    if let Some(source) = quote.source {
+        let _ = write!(credit, ", {source}");
+    }
+    if let Some(year) = quote.year {
+        let _ = write!(credit, " ({year})");
+    }
+    let tags: Vec<String> = quote.tags.iter().map(|tag| format!("#{tag}")).collect();
+    Text::from(vec![
+        Line::styled(format!("\"{}\"", quote.text), theme.data).italic(),
+        Line::raw(""),
+        Line::styled(credit, theme.text),
+        Line::raw(""),
+        Line::styled(tags.join(" "), theme.link_url),
+    ])
+}
+
+/// Address of the page without its query and hash
+#[cfg(target_arch = "wasm32")]
+fn page_url() -> String {
+    ratzilla::web_sys::window()
+        .and_then(|window| {
+            let location = window.location();
+            Some(format!(
+                "{}{}",
+                location.origin().ok()?,
+                location.pathname().ok()?
+            ))
+        })
+        .unwrap_or_else(|| HOMEPAGE.to_string())
+}
+
+/// Native terminals share links to the website itself
+#[cfg(not(target_arch = "wasm32"))]
+fn page_url() -> String {
+    HOMEPAGE.to_string()
+}
@@ -0,0 +1,395 @@
+use crate::PROMPT;
+use crate::markdown;
+use crate::models::quote::Quote;
+use crate::models::topic::Topic;
+use crate::theme::Theme;
+use crate::utils::open_link;
+use ratzilla::event::{KeyCode, KeyEvent};
+use ratzilla::ratatui::buffer::Buffer;
+use ratzilla::ratatui::layout::{Constraint, Layout, Rect};
+use ratzilla::ratatui::prelude::{Line, Span, Style, Stylize, Text, Widget};
+use ratzilla::ratatui::symbols;
+use ratzilla::ratatui::widgets::{Block, Borders, Padding, Paragraph, Wrap};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Lines kept in the scrollback, the oldest ones are dropped first
+const MAX_SCROLLBACK: usize = 1000;
+
+/// Commands that are not a topic name, used by the help and the completion
+const BUILTINS: [(&str, &str); 5] = [
+    ("help", "show this help"),
+    ("ls", "list the topics"),
+    ("open <topic>", "open the first link of a topic"),
+    ("clear", "clear the screen"),
+    ("exit", "go back to the topic list"),
+];
+
+/// A command typed in the shell.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Command {
+    /// Print the description of a topic, typed as the topic name
+    Show(Topic),
+    /// Open the link of a topic
+    Open(Topic),
+    Help,
+    Ls,
+    Clear,
+    Exit,
+}
+
+/// Why a command line could not be parsed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    Empty,
+    UnknownCommand(String),
+    UnknownTopic(String),
+    MissingArgument(&'static str),
+    TooManyArguments(String),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty command"),
+            Self::UnknownCommand(command) => write!(f, "{command}: command not found"),
+            Self::UnknownTopic(topic) => write!(f, "{topic}: no such topic"),
+            Self::MissingArgument(command) => write!(f, "{command}: missing topic"),
+            Self::TooManyArguments(command) => write!(f, "{command}: too many arguments"),
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut words = line.split_whitespace();
+        let name = words.next().ok_or(ParseError::Empty)?.to_ascii_lowercase();
+        let arguments = words.collect::<Vec<_>>();
+        let command = match name.as_str() {
+            "help" => Self::Help,
+            "ls" => Self::Ls,
+            "clear" => Self::Clear,
+            "exit" => Self::Exit,
+            "open" => {
+                let id = arguments
+                    .first()
+                    .ok_or(ParseError::MissingArgument("open"))?;
+                let topic = Topic::from_id(id)
+                    .ok_or_else(|| ParseError::UnknownTopic((*id).to_string()))?;
+                if arguments.len() > 1 {
+                    return Err(ParseError::TooManyArguments(name));
+                }
+                return Ok(Self::Open(topic));
+            }
+            _ => Topic::from_id(&name)
+                .map(Self::Show)
+                .ok_or_else(|| ParseError::UnknownCommand(name.clone()))?,
+        };
+        if arguments.is_empty() {
+            Ok(command)
+        } else {
+            Err(ParseError::TooManyArguments(name))
+        }
+    }
+}
+
+/// Interactive prompt where visitors type commands instead of browsing the list.
+pub struct Shell {
+    input: String,
+    scrollback: Vec<Line<'static>>,
+    /// Lines printed since the shell was created, the ones dropped from the scrollback included
+    printed: usize,
+    history: Vec<String>,
+    /// Entry of `history` shown while browsing it, `None` when editing a new line
+    history_index: Option<usize>,
+    /// Line being edited before browsing the history, restored after the newest entry
+    draft: String,
+    should_exit: bool,
+    /// Width of the text at the last render, wide ASCII art is dropped beyond it
+    width: u16,
+    /// Theme of the last render, used to style the command output
+    theme: Theme,
+    /// Quote shown by quote topics, printed by `show`
+    quote: Option<&'static Quote>,
+}
+
+impl Default for Shell {
+    fn default() -> Self {
+        Self {
+            input: String::new(),
+            scrollback: vec![Line::raw("Type help to list the available commands.")],
+            printed: 1,
+            history: Vec::new(),
+            history_index: None,
+            draft: String::new(),
+            should_exit: false,
+            width: u16::MAX,
+            theme: Theme::default(),
+            quote: None,
+        }
+    }
+}
+
+impl Shell {
+    pub fn on_key_event(&mut self, event: &KeyEvent) {
+        match event.code {
+            KeyCode::Char('l') if event.ctrl => self.scrollback.clear(),
+            KeyCode::Char('u') if event.ctrl => self.input.clear(),
+            KeyCode::Char(c) if !event.ctrl && !event.alt => self.on_key(c),
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Enter => self.on_enter(),
+            KeyCode::Tab => self.complete(),
+            KeyCode::Up => self.on_up(),
+            KeyCode::Down => self.on_down(),
+            _ => {}
+        }
+    }
+
+    pub fn on_key(&mut self, c: char) {
+        self.input.push(c);
+    }
+
+    /// Run the command line being edited.
+    pub fn on_enter(&mut self) {
+        let line = std::mem::take(&mut self.input);
+        self.history_index = None;
+        self.draft.clear();
+        self.print(Line::raw(format!("{PROMPT} {line}")));
+        if line.trim().is_empty() {
+            return;
+        }
+        if self.history.last() != Some(&line) {
+            self.history.push(line.clone());
+        }
+        match line.parse() {
+            Ok(command) => self.run(command),
+            Err(error) => self.print(Line::raw(error.to_string()).fg(self.theme.accent)),
+        }
+    }
+
+    /// Show the previous history entry.
+    pub fn on_up(&mut self) {
+        let index = match self.history_index {
+            Some(index) => index.saturating_sub(1),
+            None if self.history.is_empty() => return,
+            None => {
+                self.draft = std::mem::take(&mut self.input);
+                self.history.len() - 1
+            }
+        };
+        self.history_index = Some(index);
+        self.input.clone_from(&self.history[index]);
+    }
+
+    /// Show the next history entry, or the line edited before browsing the history.
+    pub fn on_down(&mut self) {
+        let Some(index) = self.history_index else {
+            return;
+        };
+        if index + 1 < self.history.len() {
+            self.history_index = Some(index + 1);
+            self.input.clone_from(&self.history[index + 1]);
+        } else {
+            self.history_index = None;
+            self.input = std::mem::take(&mut self.draft);
+        }
+    }
+
+    /// Complete the word being typed with a command or a topic name.
+    ///
+    /// A single candidate is completed, several ones are completed up to their common prefix
+    /// and listed when there is nothing left to complete.
+    pub fn complete(&mut self) {
+        let start = self.input.rfind(' ').map_or(0, |i| i + 1);
+        let word = self.input[start..].to_ascii_lowercase();
+        let candidates = completions(&self.input[..start], &word);
+        match candidates.as_slice() {
+            [] => {}
+            [candidate] => {
+                self.input.truncate(start);
+                self.input.push_str(candidate);
+                self.input.push(' ');
+            }
+            _ => {
+                let prefix = common_prefix(&candidates);
+                if prefix.len() > word.len() {
+                    self.input.truncate(start);
+                    self.input.push_str(prefix);
+                } else {
+                    self.print(Line::raw(format!("{PROMPT} {}", self.input)));
+                    self.print(Line::styled(candidates.join("  "), self.theme.text));
+                }
+            }
+        }
+    }
+
+    /// The command line being edited.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Number of lines printed so far, it only grows, even when the screen is cleared.
+    #[must_use]
+    pub const fn printed(&self) -> usize {
+        self.printed
+    }
+
+    /// Text of the scrollback, one string per line.
+    #[must_use]
+    pub fn transcript(&self) -> Vec<String> {
+        self.scrollback.iter().map(ToString::to_string).collect()
+    }
+
+    /// Show `quote` for quote topics from now on.
+    pub const fn set_quote(&mut self, quote: Option<&'static Quote>) {
+        self.quote = quote;
+    }
+
+    /// Whether the visitor asked to leave the shell, the request is cleared once read.
+    pub const fn take_exit_request(&mut self) -> bool {
+        std::mem::replace(&mut self.should_exit, false)
+    }
+
+    fn run(&mut self, command: Command) {
+        match command {
+            Command::Show(topic) => {
+                let description = markdown::to_text(
+                    &topic.get_description(self.quote),
+                    usize::from(self.width),
+                    &self.theme,
+                );
+                for line in description.lines {
+                    self.print(line);
+                }
+            }
+            Command::Open(topic) => {
+                if let Some((first, others)) = topic.links().split_first() {
+                    self.print(Line::raw(format!("Opening {}", first.url)));
+                    // The other links are only listed, browsers block several popups at once
+                    for link in others {
+                        self.print(Line::styled(
+                            format!("{} {}: {}", link.kind.symbol(), link.label, link.url),
+                            self.theme.text,
+                        ));
+                    }
+                    open_link(first.url);
+                } else {
+                    self.print(
+                        Line::raw(format!("{}: nothing to open", topic.id())).fg(self.theme.accent),
+                    );
+                }
+            }
+            Command::Help => {
+                let width = BUILTINS.iter().map(|(usage, _)| usage.len()).max();
+                for (usage, help) in BUILTINS {
+                    self.print(Line::from(vec![
+                        Span::styled(
+                            format!("{usage:<width$}  ", width = width.unwrap_or_default()),
+                            self.theme.text,
+                        ),
+                        Span::raw(help),
+                    ]));
+                }
+                self.print(Line::raw("Type a topic name to read it, see ls."));
+            }
+            Command::Ls => {
+                let ids = Topic::registry()
+                    .map(|topic| topic.id())
+                    .collect::<Vec<_>>();
+                self.print(Line::styled(ids.join("  "), self.theme.text));
+            }
+            Command::Clear => self.scrollback.clear(),
+            Command::Exit => self.should_exit = true,
+        }
+    }
+
+    fn print(&mut self, line: Line<'static>) {
+        self.scrollback.push(line);
+        self.printed += 1;
+        if self.scrollback.len() > MAX_SCROLLBACK {
+            self.scrollback
+                .drain(..self.scrollback.len() - MAX_SCROLLBACK);
+        }
+    }
+}
+
+/// Candidates for `word`, given the part of the command line before it
+fn completions(before: &str, word: &str) -> Vec<&'static str> {
+    let topics = Topic::registry().map(|topic| topic.id());
+    let candidates: Vec<&'static str> = match before.split_whitespace().collect::<Vec<_>>()[..] {
+        [] => BUILTINS
+            .iter()
+            .filter_map(|(usage, _)| usage.split(' ').next())
+            .chain(topics)
+            .collect(),
+        ["open"] => topics.collect(),
+        _ => Vec::new(),
+    };
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.starts_with(word))
+        .collect()
+}
+
+fn common_prefix<'a>(candidates: &[&'a str]) -> &'a str {
+    let first = candidates.first().copied().unwrap_or_default();
+    let len = candidates.iter().fold(first.len(), |len, candidate| {
+        first
+            .bytes()
+            .zip(candidate.bytes())
+            .take(len)
+            .take_while(|(a, b)| a == b)
+            .count()
+    });
+    &first[..len]
+}
+
+/// Rendering logic for the shell
+impl Shell {
+    /// Render the scrollback, the prompt and the key help with `theme`.
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer, theme: &Theme) {
+        self.theme = *theme;
+        let footer = Paragraph::new(
+            "Type help for the commands, Tab to complete, ↓↑ for history, Esc to go back.",
+        )
+        .fg(theme.title)
+        .centered()
+        .wrap(Wrap { trim: true });
+        let footer_height = u16::try_from(footer.line_count(area.width)).unwrap_or(u16::MAX);
+        let [main_area, footer_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(footer_height)]).areas(area);
+
+        let block = Block::new()
+            .title(Line::raw("Terminal").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.panel)
+            .padding(Padding::horizontal(1));
+        let text_area = block.inner(main_area);
+        self.width = text_area.width;
+        block.render(main_area, buf);
+
+        let mut text = Text::from(self.scrollback.clone());
+        text.push_line(Line::from(vec![
+            Span::raw(format!("{PROMPT} {}", self.input)),
+            Span::styled(" ", Style::new().reversed()),
+        ]));
+        let paragraph = Paragraph::new(text)
+            .fg(theme.data)
+            .wrap(Wrap { trim: false });
+        // Keep the prompt in view, the oldest lines scroll out at the top
+        let lines = paragraph.line_count(text_area.width);
+        let scroll = lines.saturating_sub(usize::from(text_area.height));
+        paragraph
+            .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0))
+            .render(text_area, buf);
+
+        footer.render(footer_area, buf);
+    }
+}
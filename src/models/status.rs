@@ -1,4 +1,4 @@
-use crate::{COMPLETED_TEXT_FG_COLOR, TEXT_FG_COLOR};
+use crate::theme::Theme;
 use ratzilla::ratatui::prelude::Color;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -8,6 +8,7 @@ pub enum Status {
 }
 
 impl Status {
+    #[must_use]
     pub const fn get_status_char(self) -> char {
         match self {
             Self::Todo => '☐',
@@ -15,10 +16,11 @@ impl Status {
         }
     }
 
-    pub const fn get_status_color(self) -> Color {
+    #[must_use]
+    pub const fn get_status_color(self, theme: &Theme) -> Color {
         match self {
-            Self::Todo => TEXT_FG_COLOR,
-            Self::Completed => COMPLETED_TEXT_FG_COLOR,
+            Self::Todo => theme.text,
+            Self::Completed => theme.completed,
         }
     }
 }
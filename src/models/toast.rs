@@ -0,0 +1,103 @@
+//! Short messages shown over a scene for a while, e.g. once a copy is done.
+
+use crate::clipboard::{self, PendingCopy};
+use crate::theme::Theme;
+use ratzilla::ratatui::buffer::Buffer;
+use ratzilla::ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratzilla::ratatui::prelude::{Stylize, Widget};
+use ratzilla::ratatui::widgets::{Block, Clear, Padding, Paragraph};
+use std::time::Duration;
+use web_time::Instant;
+
+/// How long a toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_millis(2500);
+
+/// A short message.
+struct Toast {
+    message: String,
+    /// Whether the message reports a success or a failure
+    success: bool,
+    since: Instant,
+}
+
+/// Shows a toast at a time, and the outcome of copies once the browser answered.
+#[derive(Default)]
+pub struct Toaster {
+    /// Copy waiting for the browser, with what is being copied
+    copying: Option<(String, PendingCopy)>,
+    toast: Option<Toast>,
+}
+
+impl Toaster {
+    /// Show `message` in place of the current toast.
+    pub fn show(&mut self, message: String, success: bool) {
+        self.toast = Some(Toast {
+            message,
+            success,
+            since: Instant::now(),
+        });
+    }
+
+    /// Copy `text` to the clipboard, the toast names it `what`.
+    pub fn copy(&mut self, what: String, text: &str) {
+        self.copying = Some((what, clipboard::copy(text)));
+        self.poll_copy();
+    }
+
+    /// Message of the toast on screen, if any.
+    #[must_use]
+    pub fn message(&self) -> Option<&str> {
+        self.toast.as_ref().map(|toast| toast.message.as_str())
+    }
+
+    /// Turn the answer of the browser to the pending copy into a toast
+    fn poll_copy(&mut self) {
+        let Some((what, pending)) = &self.copying else {
+            return;
+        };
+        let Some(copied) = pending.result() else {
+            return;
+        };
+        let message = if copied {
+            format!("Copied {what}")
+        } else {
+            format!("Could not copy {what}")
+        };
+        self.copying = None;
+        self.show(message, copied);
+    }
+
+    /// Render the toast at the bottom of `area`, centered, until it expires.
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer, theme: &Theme) {
+        self.poll_copy();
+        self.toast = self
+            .toast
+            .take()
+            .filter(|toast| toast.since.elapsed() < TOAST_DURATION);
+        let Some(toast) = &self.toast else {
+            return;
+        };
+        let width = u16::try_from(toast.message.chars().count()).unwrap_or(u16::MAX);
+        let [area] = Layout::horizontal([Constraint::Length(width.saturating_add(4))])
+            .flex(Flex::Center)
+            .areas(area);
+        let [area] = Layout::vertical([Constraint::Length(3)])
+            .flex(Flex::End)
+            .areas(area);
+        let color = if toast.success {
+            theme.text
+        } else {
+            theme.accent
+        };
+        Clear.render(area, buf);
+        Paragraph::new(toast.message.as_str())
+            .block(
+                Block::bordered()
+                    .border_style(theme.header)
+                    .bg(theme.panel)
+                    .padding(Padding::horizontal(1)),
+            )
+            .fg(color)
+            .render(area, buf);
+    }
+}
@@ -1,166 +1,115 @@
-use crate::models::status::Status;
+use crate::models::quote::Quote;
 use std::fmt::Display;
-use std::sync::Mutex;
-use strum::EnumIter;
-use wasm_bindgen::__rt::LazyLock;
 
-static LAST_QUOTE: LazyLock<Mutex<String>> = LazyLock::new(|| Mutex::new(String::new()));
-#[derive(EnumIter, Debug, PartialEq, Eq, Clone)]
-pub enum Topic {
-    About,
-    Contact,
-    Cv,
-    Donate,
-    Quote,
-    Social,
-    Summary,
-    Credits,
+include!(concat!(env!("OUT_DIR"), "/topics.rs"));
+
+/// A section of the website, as written in the `content/` directory.
+///
+/// Topics are compiled in by the build script, adding a markdown file to `content/`
+/// is enough to add a topic.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Topic {
+    content: &'static TopicContent,
+}
+
+/// Data of a single `content/*.md` file
+#[derive(Debug, PartialEq, Eq)]
+struct TopicContent {
+    id: &'static str,
+    title: &'static str,
+    links: &'static [Link],
+    kind: TopicKind,
+    description: &'static str,
 }
 
-const QUOTES: &[&str] = &[
-    "\"Victory belongs to the most persevering.\" - Napoleon Bonaparte",
-    "\"Sharing knowledge is the most fundamental act of friendship. Because it is a way you can give something without loosing something.\" - Richard Stallman",
-    "\"The average consumer does not know the difference between browser, Internet and search box.\" - Mitchell Baker",
-    "\"Never confuse a single defeat with a final defeat\" -  F. Scott Fitzgerald",
-    "\"I mean, if 10 years from now, when you are doing something quick and dirty, you suddenly visualize that I am looking over your shoulders and say to yourself \"Dijkstra would not have liked this\", well, that would be enough immortality for me.\" - Edsger W. Dijkstra",
-    "\"The question of whether a computer can think is no more interesting than the question of whether a submarine can swim.\" - Edsger W. Dijkstra",
-    "\"The use of COBOL cripples the mind; its teaching should, therefore, be regarded as a criminal offense.\" - Edsger W. Dijkstra",
-    "\"The most important property of a program is whether it accomplishes the intention of its user.\" - Graydon Hoare",
-    "\"I think, fundamentally, open source does tend to be more stable software. It's the right way to do things.\" - Linus Torvalds",
-    "\"Fully secure systems don't exist today and they won't exist in the future.\" - Adi Shamir",
-    "\"Information is the resolution of uncertainty.\" - Claude Shannon",
-    "\"Weak typing is a devil plaguing software correctness. It tempts you with ease of development, while secretly hiding undefined behaviour in the code.\" - Daniele Giachetto",
-    "\"Only sneaky people and impostors can oppose the progress of sciences and can discredit them, because they are the only ones to whom the sciences do harm.\" - Friedrich der Große",
-];
+/// A link of a topic, declared in the front-matter of its content file.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Link {
+    pub kind: LinkKind,
+    pub label: &'static str,
+    pub url: &'static str,
+}
+
+/// What a link leads to
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LinkKind {
+    Web,
+    Mail,
+    Download,
+}
+
+impl LinkKind {
+    /// Symbol shown before the label of the link.
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Web => "↗",
+            Self::Mail => "✉",
+            Self::Download => "⤓",
+        }
+    }
+
+    /// Name of the kind, as written in the front-matter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Web => "link",
+            Self::Mail => "mail",
+            Self::Download => "download",
+        }
+    }
+}
+
+/// How the description of a topic is produced
+#[derive(Debug, PartialEq, Eq)]
+enum TopicKind {
+    /// The description is the body of the content file
+    Text,
+    /// The description is a quote, drawn by whoever shows the topic
+    Quote,
+}
 
 impl Display for Topic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::About => "About",
-                Self::Contact => "Contact",
-                Self::Cv => "Cv",
-                Self::Donate => "Donate",
-                Self::Social => "Social",
-                Self::Quote => "Quote",
-                Self::Summary => "Summary",
-                Self::Credits => "Credits",
-            }
-        )
+        write!(f, "{}", self.content.title)
     }
 }
 
 impl Topic {
-    pub fn get_link(&self) -> String {
-        String::from(match self {
-            Self::Donate => "https://paypal.me/danielegiachetto",
-            Self::Social => "https://linkedin.com/in/danielegiachetto",
-            Self::Contact => "mailto:work@danielegiachetto.com",
-            Self::Cv => "https://github.com/RakuJa/CV/blob/master/CV.pdf",
-            Self::Credits => "https://github.com/orhun/ratzilla",
-            _ => "",
-        })
+    /// Every topic, in list order.
+    pub fn registry() -> impl Iterator<Item = Self> {
+        TOPICS.iter().map(|content| Self { content })
     }
-    pub fn get_description(&self, status: Status) -> String {
-        let tmp_val;
-        String::from(match self {
-            Self::About => {
-                "
-                     ███████████             █████                      ███
-                    ░░███░░░░░███           ░░███                      ░░░
-                     ░███    ░███   ██████   ░███ █████ █████ ████     █████  ██████
-                     ░██████████   ░░░░░███  ░███░░███ ░░███ ░███     ░░███  ░░░░░███
-                     ░███░░░░░███   ███████  ░██████░   ░███ ░███      ░███   ███████
-                     ░███    ░███  ███░░███  ░███░░███  ░███ ░███      ░███  ███░░███
-                     █████   █████░░████████ ████ █████ ░░████████     ░███ ░░████████
-                    ░░░░░   ░░░░░  ░░░░░░░░ ░░░░ ░░░░░   ░░░░░░░░      ░███  ░░░░░░░░
-                                                                   ███ ░███
-                                                                  ░░██████
-                                                                   ░░░░░░
-
-                    I'm Daniele Giachetto aka RakuJa, a Software developer & Cybersecurity major.
-                    This is an interactive website, in which you'll use the TUI with your keyboard to know more about myself."
 
-            }
-            Self::Cv => {
-                "Software developer, space enthusiast and much more. Copy the link or open with CTRL + ENTER to learn more about myself:\n
-https://github.com/RakuJa/CV/blob/master/CV.pdf"
+    /// Find a topic by its id, the name of its content file without extension.
+    #[must_use]
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::registry().find(|topic| topic.id().eq_ignore_ascii_case(id))
+    }
 
-            },
-            Self::Contact => {
-                "I have various email addresses, each divided by topic:\n
-- mailto:work@danielegiachetto.com \n\
-- mailto:education@danielegiachetto.com \n\
-- mailto:personal@danielegiachetto.com
-                "
-            }
-            Self::Donate => {
-                "Thank you for your interest, here are the ways in which you can support my work:\n
-- https://paypal.me/danielegiachetto \n\
-- https://ko-fi.com/rakuja
-                "
-            },
-            Self::Quote => {
-                tmp_val = match status {
-                    Status::Completed => LAST_QUOTE.lock().map_or(String::new(), |lq| lq.clone()),
-                    Status::Todo => {
-                        let quote = get_random_quote();
-                        if let Ok(mut lq) = LAST_QUOTE.lock() {
-                            (*lq).clone_from(&quote);
-                        }
-                        quote
-                    }
-                };
-                tmp_val.as_str()
-            },
-            Self::Social => {
-                "https://linkedin.com/in/danielegiachetto \n\
-                https://github.com/rakuja
-                "
+    #[must_use]
+    pub const fn id(&self) -> &'static str {
+        self.content.id
+    }
 
-            },
-            Self::Summary => {
-                "
-                                                     ./o.                  🚗 My daily drivers: EndeavourOS | CachyOS
-                                                   ./sssso-                --------------------
-                                                  :osssssss+-              📡 ABOUT
-                                                :+sssssssssso/.            🌌 whoami => Daniele Giachetto
-                                              -/ossssssssssssso/.          📑 Resume 🔽
-                                            -/+sssssssssssssssso+:         🔗 https://github.com/RakuJa/CV/blob/master/CV.pdf
-                                          -:/+sssssssssssssssssso+/.       -----------
-                                        .://osssssssssssssssssssso++-      🎉 SOCIALS 🔽
-                                      .://+ssssssssssssssssssssssso++:     💻 https://github.com/rakuja
-                                    .:///ossssssssssssssssssssssssso++:    🏢 https://linkedin.com/in/danielegiachetto
-                                   :////ssssssssssssssssssssssssssso+++.   -----------
-                                 -////+ssssssssssssssssssssssssssso++++-   🎁 DONATE 🔽
-                                  ..-+oosssssssssssssssssssssssso+++++/    💰 https://paypal.me/danielegiachetto
-                                   ./++++++++++++++++++++++++++++++/:.     💸 https://ko-fi.com/rakuja
-                                   :::::::::::::::::::::::::------
+    /// Links of the topic, in front-matter order.
+    #[must_use]
+    pub const fn links(&self) -> &'static [Link] {
+        self.content.links
+    }
 
- mailto:work@danielegiachetto.com | mailto:education@danielegiachetto.com | mailto:personal@danielegiachetto.com
-                "
-            },
-            Self::Credits => "I can't hold my inner voice. He tells me to rewrite the complete universe with Rust \n\
-            MADE WITH ♥ using the fantastic Ratzilla library => https://github.com/orhun/ratzilla
-            ⠀⠀⣠⠤⠖⠒⠦⢤⡀⠀⠀⠀⠀⠀⠀⢀⠤⠴⠒⠢⠤⣀⠀⠀
-            ⠀⣼⠁⠀⠀⡠⢖⡉⠁⠀⠀⠀⠀⠀⠀⠈⢙⡲⣄⠀⠀⠈⣇⠀
-            ⠀⣟⣄⠀⠐⠓⢋⡇⠀⠀⠀⠀⠀⠀⠀⠀⢹⡙⠚⠀⠀⡠⣻⠀
-            ⠀⠈⡶⢭⣒⡺⠟⣀⣰⣿⠦⠤⠤⢼⣿⣆⡈⠻⢖⣒⡭⡾⠁⠀
-            ⠀⠀⠱⡘⢄⡰⠊⠁⠀⠀⠀⠀⠀⠀⠀⠀⠈⠑⣄⡰⣃⠇⠀⠀
-            ⠀⣀⠤⠬⢽⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣸⡯⠥⠤⡀⠀
-            ⠰⠕⢋⡭⠿⡟⢄⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⡠⣻⠯⢭⡙⠺⠆
-            ⠀⢰⡳⠊⡩⠛⣦⡉⠒⠤⠤⠄⠤⠤⠤⠒⢉⣔⠛⢍⠓⣝⡄⠀
-            ⠀⠈⠁⡼⡴⠉⠀⠈⠓⠲⠤⠤⠤⠤⠖⠚⠁⠈⠉⣎⣧⠈⠁⠀
-            ⠀⠀⠀⠉⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠁⠀⠀⠀
-            ",
-        })
+    /// Whether the description of the topic is a quote.
+    #[must_use]
+    pub const fn is_quote(&self) -> bool {
+        matches!(self.content.kind, TopicKind::Quote)
     }
-}
 
-fn get_random_quote() -> String {
-    (*fastrand::choice(QUOTES)
-        .unwrap_or(&"\"Victory belongs to the most persevering.\" - Napoleon Bonaparte"))
-    .to_string()
+    /// Markdown description of the topic, quote topics describe themselves with `quote`.
+    #[must_use]
+    pub fn get_description(&self, quote: Option<&Quote>) -> String {
+        match self.content.kind {
+            TopicKind::Text => String::from(self.content.description),
+            TopicKind::Quote => quote.map(ToString::to_string).unwrap_or_default(),
+        }
+    }
 }
@@ -0,0 +1,151 @@
+//! How much the scenes move, following the visitor motion preferences.
+//!
+//! The policy is picked from the `?motion=` URL parameter, then from the browser
+//! `prefers-reduced-motion`, and `m` cycles through every policy.
+
+use crate::utils::{matches_media, url_param};
+use ratzilla::ratatui::style::Color;
+use tachyonfx::fx::RepeatMode;
+use tachyonfx::{Effect, EffectTimer, Interpolation, Motion, fx};
+
+/// Which effects the scenes are rendered with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MotionPolicy {
+    /// Sweeps, dissolves and colour shifts, the intro keeps moving
+    #[default]
+    Full,
+    /// Short fades when a scene appears, nothing moves afterwards
+    Reduced,
+    /// Scenes appear at once
+    None,
+}
+
+/// Every policy, in the order `m` cycles through them
+const POLICIES: [MotionPolicy; 3] = [
+    MotionPolicy::Full,
+    MotionPolicy::Reduced,
+    MotionPolicy::None,
+];
+
+impl MotionPolicy {
+    /// Name used by the `?motion=` URL parameter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Reduced => "reduced",
+            Self::None => "none",
+        }
+    }
+
+    /// Find a policy by name, case insensitive.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        POLICIES
+            .into_iter()
+            .find(|policy| policy.as_str().eq_ignore_ascii_case(name))
+    }
+
+    /// The policy after this one, back to the first after the last.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Full => Self::Reduced,
+            Self::Reduced => Self::None,
+            Self::None => Self::Full,
+        }
+    }
+
+    /// Policy asked by the `?motion=` URL parameter, or the one matching the browser
+    /// `prefers-reduced-motion`.
+    #[must_use]
+    pub fn from_browser() -> Self {
+        let asked = url_param("motion").and_then(|name| Self::from_name(&name));
+        asked.unwrap_or_else(|| {
+            if matches_media("(prefers-reduced-motion: reduce)") {
+                Self::Reduced
+            } else {
+                Self::Full
+            }
+        })
+    }
+
+    /// Effect of the intro scene.
+    #[must_use]
+    pub fn intro_effect(self) -> Effect {
+        match self {
+            Self::Full => fx::sequence(&[
+                fx::sweep_in(
+                    Motion::LeftToRight,
+                    50,
+                    30,
+                    Color::Black,
+                    EffectTimer::from_ms(20000, Interpolation::SineInOut),
+                ),
+                fx::sleep(20000),
+                fx::repeat(
+                    fx::sequence(&[
+                        fx::sleep(10000),
+                        fx::parallel(&[
+                            fx::dissolve((10000, Interpolation::BounceOut)),
+                            fx::hsl_shift(
+                                Some([120.0, 25.0, 25.0]),
+                                None,
+                                (10000, Interpolation::Linear),
+                            ),
+                        ]),
+                        fx::parallel(&[
+                            fx::dissolve((10000, Interpolation::BounceOut)),
+                            fx::coalesce((20000, Interpolation::BounceOut)),
+                            fx::hsl_shift(
+                                Some([120.0, 25.0, 25.0]),
+                                None,
+                                (20000, Interpolation::Linear),
+                            ),
+                        ]),
+                        fx::sleep(40000),
+                    ]),
+                    RepeatMode::Forever,
+                ),
+            ]),
+            Self::Reduced => fx::fade_from_fg(Color::Black, (3000, Interpolation::QuadOut)),
+            Self::None => fx::sleep(0),
+        }
+    }
+
+    /// Effect of the menu scene, when it appears.
+    #[must_use]
+    pub fn menu_effect(self) -> Effect {
+        match self {
+            Self::Full => fx::sequence(&[
+                fx::coalesce((3000, Interpolation::SineOut)),
+                fx::sleep(1000),
+            ]),
+            Self::Reduced => fx::fade_from_fg(Color::Black, (1000, Interpolation::QuadOut)),
+            Self::None => fx::sleep(0),
+        }
+    }
+
+    /// Effect of a scene opened from the menu, e.g. the shell, when it appears.
+    #[must_use]
+    pub fn scene_effect(self) -> Effect {
+        match self {
+            Self::Full => fx::coalesce((800, Interpolation::SineOut)),
+            Self::Reduced => fx::fade_from_fg(Color::Black, (500, Interpolation::QuadOut)),
+            Self::None => fx::sleep(0),
+        }
+    }
+
+    /// Effect of the goodbye scene, the visitor leaves once it is done.
+    ///
+    /// Every policy keeps the message on screen for a while, so that the exit can be undone.
+    #[must_use]
+    pub fn goodbye_effect(self) -> Effect {
+        let fade_out = match self {
+            Self::Full => fx::dissolve((1500, Interpolation::QuadIn)),
+            Self::Reduced => fx::fade_to_fg(Color::Black, (1500, Interpolation::QuadIn)),
+            Self::None => fx::sleep(1500),
+        };
+        fx::sequence(&[fx::sleep(1500), fade_out])
+    }
+}
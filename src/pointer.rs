@@ -0,0 +1,218 @@
+//! Mouse and touch input, translated into terminal cells.
+//!
+//! Ratzilla only forwards key events, pointer events are listened to on the terminal host
+//! element so that they keep working when the backend is swapped. Touch gestures are mapped
+//! onto the mouse ones: a tap is a click, two quick taps a double click and a vertical swipe
+//! scrolls like the wheel.
+
+use crate::backend::TERMINAL_HOST_ID;
+use ratzilla::ratatui::layout::{Position, Size};
+use ratzilla::web_sys;
+use ratzilla::web_sys::wasm_bindgen::JsValue;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+
+/// Longest delay between two taps of a double tap, in milliseconds
+const DOUBLE_TAP_MS: f64 = 300.0;
+
+/// Shortest vertical move of a swipe, in pixels, shorter moves are taps
+const SWIPE_MIN_PX: f64 = 30.0;
+
+/// A pointer action on a terminal cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MouseEvent {
+    pub kind: MouseEventKind,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseEventKind {
+    Click,
+    DoubleClick,
+    ScrollUp,
+    ScrollDown,
+}
+
+/// Where the last touch started, and when and where the last tap happened
+#[derive(Default, Clone, Copy)]
+struct Touch {
+    start: Option<(f64, f64)>,
+    last_tap: Option<(f64, Position)>,
+}
+
+/// Find the cell under viewport coordinates
+type ToCell = Rc<dyn Fn(f64, f64) -> Option<Position>>;
+
+/// Forward an action to the callback
+type Emit = Rc<dyn Fn(MouseEventKind, Position)>;
+
+/// Call `callback` for every mouse or touch action on the terminal.
+///
+/// `terminal_size` gives the size of the terminal in cells, it is used to find the cell
+/// under the pointer.
+///
+/// # Errors
+///
+/// Returns an error if the terminal host element cannot be found.
+pub fn on_mouse_event<S, F>(terminal_size: S, callback: F) -> Result<(), JsValue>
+where
+    S: Fn() -> Size + 'static,
+    F: FnMut(MouseEvent) + 'static,
+{
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("No document")?;
+    let host = document
+        .get_element_by_id(TERMINAL_HOST_ID)
+        .ok_or("No terminal host")?;
+    let to_cell: ToCell = Rc::new(move |x, y| cell_at(terminal_size(), x, y));
+    let callback = RefCell::new(callback);
+    let emit: Emit = Rc::new(move |kind, position| {
+        (callback.borrow_mut())(MouseEvent { kind, position });
+    });
+    let touch = Rc::new(Cell::new(Touch::default()));
+
+    let listeners = [
+        (
+            "click",
+            click_listener(MouseEventKind::Click, &to_cell, &emit),
+        ),
+        (
+            "dblclick",
+            click_listener(MouseEventKind::DoubleClick, &to_cell, &emit),
+        ),
+        ("wheel", wheel_listener(&to_cell, &emit)),
+        ("touchstart", touch_start_listener(&touch)),
+        ("touchend", touch_end_listener(&touch, &to_cell, &emit)),
+    ];
+    for (event, listener) in listeners {
+        host.add_event_listener_with_callback(event, listener.as_ref().unchecked_ref())?;
+        listener.forget();
+    }
+    Ok(())
+}
+
+fn click_listener(
+    kind: MouseEventKind,
+    to_cell: &ToCell,
+    emit: &Emit,
+) -> Closure<dyn FnMut(web_sys::Event)> {
+    let (to_cell, emit) = (to_cell.clone(), emit.clone());
+    Closure::new(move |event: web_sys::Event| {
+        if let Some(event) = event.dyn_ref::<web_sys::MouseEvent>() {
+            let x = f64::from(event.client_x());
+            let y = f64::from(event.client_y());
+            if let Some(position) = to_cell(x, y) {
+                emit(kind, position);
+            }
+        }
+    })
+}
+
+fn wheel_listener(to_cell: &ToCell, emit: &Emit) -> Closure<dyn FnMut(web_sys::Event)> {
+    let (to_cell, emit) = (to_cell.clone(), emit.clone());
+    Closure::new(move |event: web_sys::Event| {
+        if let Some(event) = event.dyn_ref::<web_sys::WheelEvent>() {
+            event.prevent_default();
+            let x = f64::from(event.client_x());
+            let y = f64::from(event.client_y());
+            let kind = if event.delta_y() < 0.0 {
+                MouseEventKind::ScrollUp
+            } else {
+                MouseEventKind::ScrollDown
+            };
+            if let Some(position) = to_cell(x, y) {
+                emit(kind, position);
+            }
+        }
+    })
+}
+
+fn touch_start_listener(touch: &Rc<Cell<Touch>>) -> Closure<dyn FnMut(web_sys::Event)> {
+    let touch = touch.clone();
+    Closure::new(move |event: web_sys::Event| {
+        if let Some(point) = touch_point(&event) {
+            touch.set(Touch {
+                start: Some(point),
+                ..touch.get()
+            });
+        }
+    })
+}
+
+/// A vertical swipe scrolls, a tap clicks and a second quick tap double clicks
+fn touch_end_listener(
+    touch: &Rc<Cell<Touch>>,
+    to_cell: &ToCell,
+    emit: &Emit,
+) -> Closure<dyn FnMut(web_sys::Event)> {
+    let (touch, to_cell, emit) = (touch.clone(), to_cell.clone(), emit.clone());
+    Closure::new(move |event: web_sys::Event| {
+        let (Some((x, y)), Some((start_x, start_y))) = (touch_point(&event), touch.get().start)
+        else {
+            return;
+        };
+        // The browser would otherwise emulate a click from the tap
+        event.prevent_default();
+        let Some(position) = to_cell(x, y) else {
+            return;
+        };
+        let (dx, dy) = (x - start_x, y - start_y);
+        if dy.abs() >= SWIPE_MIN_PX && dy.abs() > dx.abs() {
+            // Swiping up moves the content up, like scrolling down with the wheel
+            let kind = if dy < 0.0 {
+                MouseEventKind::ScrollDown
+            } else {
+                MouseEventKind::ScrollUp
+            };
+            touch.set(Touch::default());
+            emit(kind, position);
+        } else if dy.abs() < SWIPE_MIN_PX && dx.abs() < SWIPE_MIN_PX {
+            let now = event.time_stamp();
+            let double = touch
+                .get()
+                .last_tap
+                .is_some_and(|(time, last)| now - time <= DOUBLE_TAP_MS && last.y == position.y);
+            emit(MouseEventKind::Click, position);
+            if double {
+                emit(MouseEventKind::DoubleClick, position);
+            }
+            touch.set(Touch {
+                start: None,
+                last_tap: (!double).then_some((now, position)),
+            });
+        }
+    })
+}
+
+/// Viewport coordinates of the touch that changed, e.g. the finger lifted on `touchend`
+fn touch_point(event: &web_sys::Event) -> Option<(f64, f64)> {
+    let touch = event
+        .dyn_ref::<web_sys::TouchEvent>()?
+        .changed_touches()
+        .get(0)?;
+    Some((f64::from(touch.client_x()), f64::from(touch.client_y())))
+}
+
+/// Cell under the given viewport coordinates, if they are on the terminal
+fn cell_at(size: Size, x: f64, y: f64) -> Option<Position> {
+    let element = web_sys::window()?
+        .document()?
+        .get_element_by_id(TERMINAL_HOST_ID)?
+        .first_element_child()?;
+    let rect = element.get_bounding_client_rect();
+    if rect.width() <= 0.0 || rect.height() <= 0.0 || size.width == 0 || size.height == 0 {
+        return None;
+    }
+    let column = (x - rect.left()) / rect.width() * f64::from(size.width);
+    let row = (y - rect.top()) / rect.height() * f64::from(size.height);
+    if !(0.0..f64::from(size.width)).contains(&column)
+        || !(0.0..f64::from(size.height)).contains(&row)
+    {
+        return None;
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Some(Position::new(column as u16, row as u16))
+}
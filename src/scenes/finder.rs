@@ -0,0 +1,65 @@
+//! Popup finding a topic by name or content.
+
+use super::{Context, Scene, Transition};
+use crate::models::finder::Finder;
+use crate::models::quote::Quote;
+use crate::pointer::MouseEvent;
+use ratzilla::event::{KeyCode, KeyEvent};
+use ratzilla::ratatui::Frame;
+use tachyonfx::CenteredShrink;
+
+/// Shown over the topic list, typed keys edit the query and Enter reads the selected result.
+pub struct FinderScene {
+    finder: Finder,
+}
+
+impl FinderScene {
+    /// Finder over every topic, quote topics showing `quote`.
+    #[must_use]
+    pub fn new(quote: Option<&'static Quote>) -> Self {
+        Self {
+            finder: Finder::new(quote),
+        }
+    }
+}
+
+impl Scene for FinderScene {
+    fn on_key_event(&mut self, ctx: &mut Context, event: &KeyEvent) -> Transition {
+        match event.code {
+            KeyCode::Esc => Transition::Pop,
+            // Every preset claims Ctrl+K, the browser leaves it to this toggle
+            KeyCode::Char('k') if event.ctrl => Transition::Pop,
+            KeyCode::Enter => {
+                if let Some(found) = self.finder.selected().cloned() {
+                    // Selecting does not draw, a quote found is the one shown
+                    ctx.app.select_topic(Some(found.topic));
+                    if let Some(line) = found.line {
+                        ctx.app.scroll_detail_to(line);
+                    }
+                }
+                Transition::Pop
+            }
+            _ => {
+                self.finder.on_key_event(event);
+                Transition::Stay
+            }
+        }
+    }
+
+    fn on_mouse_event(&mut self, _ctx: &mut Context, _event: MouseEvent) -> Transition {
+        Transition::Pop
+    }
+
+    fn render(&mut self, f: &mut Frame<'_>, ctx: &mut Context) {
+        let area = f.area();
+        let popup = area.inner_centered(
+            area.width.saturating_sub(4).min(72),
+            area.height.saturating_sub(2).min(20),
+        );
+        self.finder.render(popup, f.buffer_mut(), &ctx.theme);
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
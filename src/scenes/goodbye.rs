@@ -0,0 +1,72 @@
+//! Goodbye scene, shown after quitting.
+
+use super::{Context, IntroScene, Scene, Transition, clear};
+use crate::motion::MotionPolicy;
+use crate::pointer::MouseEvent;
+use crate::screen_reader::View;
+use ratzilla::event::KeyEvent;
+use ratzilla::ratatui::Frame;
+use ratzilla::ratatui::prelude::{Line, Stylize, Text};
+use tachyonfx::{CenteredShrink, Duration, Effect, EffectRenderer, Shader};
+
+/// Thanks the visitor, then goes back to the intro once its effect is over.
+///
+/// Any key or touch undoes the exit until then.
+pub struct GoodbyeScene {
+    effect: Effect,
+}
+
+impl GoodbyeScene {
+    #[must_use]
+    pub fn new(motion: MotionPolicy) -> Self {
+        Self {
+            effect: motion.goodbye_effect(),
+        }
+    }
+}
+
+impl Scene for GoodbyeScene {
+    fn on_key_event(&mut self, _ctx: &mut Context, _event: &KeyEvent) -> Transition {
+        Transition::Pop
+    }
+
+    fn on_mouse_event(&mut self, _ctx: &mut Context, _event: MouseEvent) -> Transition {
+        Transition::Pop
+    }
+
+    fn update(&mut self, ctx: &mut Context) -> Transition {
+        if self.effect.done() {
+            ctx.exited = true;
+            Transition::Reset(Box::new(IntroScene::new(ctx.motion)))
+        } else {
+            Transition::Stay
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame<'_>, ctx: &mut Context) {
+        clear(f, &ctx.theme);
+        let area = f.area().inner_centered(43, 4);
+        let text = Text::from(vec![
+            Line::from("Goodbye, thanks for visiting!")
+                .bold()
+                .fg(ctx.theme.accent),
+            Line::default(),
+            Line::from(".. press any key to stay ..")
+                .italic()
+                .fg(ctx.theme.title),
+        ]);
+        f.render_widget(text.centered(), area);
+        f.render_effect(&mut self.effect, area, Duration::from_millis(30));
+    }
+
+    fn restart_effects(&mut self, motion: MotionPolicy) {
+        self.effect = motion.goodbye_effect();
+    }
+
+    fn screen_reader_view(&self, _ctx: &Context) -> Option<View> {
+        Some(View {
+            scene: "Goodbye, thanks for visiting. Press any key to stay.".into(),
+            ..View::default()
+        })
+    }
+}
@@ -0,0 +1,71 @@
+//! Popup listing every key binding of the current preset.
+
+use super::{Context, Scene, Transition};
+use crate::keymap::{Action, HELP};
+use crate::pointer::MouseEvent;
+use ratzilla::event::KeyEvent;
+use ratzilla::ratatui::Frame;
+use ratzilla::ratatui::prelude::{Line, Span, Stylize};
+use ratzilla::ratatui::widgets::{Block, Clear, Padding, Paragraph, Wrap};
+use tachyonfx::CenteredShrink;
+
+/// Shown over the topic list, any key but switching presets closes it.
+pub struct HelpScene;
+
+impl Scene for HelpScene {
+    fn on_key_event(&mut self, ctx: &mut Context, event: &KeyEvent) -> Transition {
+        if ctx.keymap.action(event) == Some(Action::NextKeymap) {
+            ctx.keymap = ctx.keymap.next();
+            Transition::Stay
+        } else {
+            Transition::Pop
+        }
+    }
+
+    fn on_mouse_event(&mut self, _ctx: &mut Context, _event: MouseEvent) -> Transition {
+        Transition::Pop
+    }
+
+    fn render(&mut self, f: &mut Frame<'_>, ctx: &mut Context) {
+        let keymap = ctx.keymap;
+        let rows: Vec<(String, &str)> = HELP
+            .iter()
+            .map(|(actions, what)| (keymap.keys_label(actions), *what))
+            .collect();
+        let keys_width = rows
+            .iter()
+            .map(|(keys, _)| keys.chars().count())
+            .max()
+            .unwrap_or(0);
+        let lines: Vec<Line> = rows
+            .into_iter()
+            .map(|(keys, what)| {
+                Line::from(vec![
+                    Span::styled(format!("{keys:>keys_width$}  "), ctx.theme.text),
+                    Span::styled(what, ctx.theme.data),
+                ])
+            })
+            .collect();
+        let width = lines.iter().map(Line::width).max().unwrap_or(0) + 4;
+        let height = lines.len() + 2;
+        let area = f.area().inner_centered(
+            u16::try_from(width).unwrap_or(u16::MAX),
+            u16::try_from(height).unwrap_or(u16::MAX),
+        );
+        let block = Block::bordered()
+            .title(Line::raw(format!(" Keys, {} preset ", keymap.name)).centered())
+            .title_bottom(Line::raw(" p for the next preset, any key to close ").centered())
+            .border_style(ctx.theme.header)
+            .bg(ctx.theme.panel)
+            .padding(Padding::horizontal(1));
+        f.render_widget(Clear, area);
+        let help = Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false });
+        f.render_widget(help, area);
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,69 @@
+//! Intro scene, with the website title and the invitation to press a key.
+
+use super::{Context, ListScene, Scene, Transition, clear};
+use crate::motion::MotionPolicy;
+use crate::pointer::MouseEvent;
+use crate::screen_reader::View;
+use ratzilla::event::KeyEvent;
+use ratzilla::ratatui::Frame;
+use ratzilla::ratatui::layout::Rect;
+use ratzilla::ratatui::prelude::{Line, Stylize, Text};
+use tachyonfx::{CenteredShrink, Duration, Effect, EffectRenderer};
+
+/// First scene, any key or touch opens the topic list.
+pub struct IntroScene {
+    effect: Effect,
+}
+
+impl IntroScene {
+    #[must_use]
+    pub fn new(motion: MotionPolicy) -> Self {
+        Self {
+            effect: motion.intro_effect(),
+        }
+    }
+}
+
+impl Scene for IntroScene {
+    fn on_key_event(&mut self, _ctx: &mut Context, _event: &KeyEvent) -> Transition {
+        Transition::Push(Box::new(ListScene))
+    }
+
+    fn on_mouse_event(&mut self, _ctx: &mut Context, _event: MouseEvent) -> Transition {
+        Transition::Push(Box::new(ListScene))
+    }
+
+    fn render(&mut self, f: &mut Frame<'_>, ctx: &mut Context) {
+        clear(f, &ctx.theme);
+        let area = f.area().inner_centered(43, 3);
+        let main_text = Text::from(vec![
+            Line::from("| R A K U J A |").bold(),
+            Line::from("Daniele Giachetto").italic(),
+            Line::from("https://danielegiachetto.com").bold(),
+        ]);
+        let area_below = Rect {
+            x: area.x,
+            y: area.y + area.height + 3,
+            width: area.width,
+            height: 2,
+        };
+        let secondary_text = Text::from(vec![
+            Line::from(".. PRESS ANY KEY TO START ..").italic(),
+            Line::from(".. or tap and swipe to explore .."),
+        ]);
+        f.render_widget(main_text.fg(ctx.theme.accent).centered(), area);
+        f.render_widget(secondary_text.fg(ctx.theme.title).centered(), area_below);
+        f.render_effect(&mut self.effect, area, Duration::from_millis(30));
+    }
+
+    fn restart_effects(&mut self, motion: MotionPolicy) {
+        self.effect = motion.intro_effect();
+    }
+
+    fn screen_reader_view(&self, _ctx: &Context) -> Option<View> {
+        Some(View {
+            scene: "Daniele Giachetto's website. Press any key to start.".into(),
+            ..View::default()
+        })
+    }
+}
@@ -0,0 +1,113 @@
+//! Topic list scene, with the selected topic beside or below it.
+
+use super::{
+    Context, FinderScene, GoodbyeScene, HelpScene, QuotesScene, Scene, ShellScene, Transition,
+    clear,
+};
+use crate::keymap::Action;
+use crate::markdown;
+use crate::models::topic::Topic;
+use crate::motion::MotionPolicy;
+use crate::pointer::MouseEvent;
+use crate::screen_reader::View;
+use ratzilla::event::KeyEvent;
+use ratzilla::ratatui::Frame;
+use ratzilla::ratatui::layout::{Constraint, Flex, Layout};
+use tachyonfx::Effect;
+
+/// Where visitors browse the topics, most keys lead to another scene from here.
+pub struct ListScene;
+
+impl ListScene {
+    /// Run an action of the key bindings.
+    fn on_action(ctx: &mut Context, action: Action) -> Transition {
+        match action {
+            Action::Up => ctx.app.on_up(),
+            Action::Down => ctx.app.on_down(),
+            Action::Back => ctx.app.on_left(),
+            Action::ToggleStatus => ctx.app.on_right(),
+            Action::Read => ctx.app.on_enter(),
+            Action::OpenLink => ctx.app.open_link(),
+            Action::Copy => ctx.app.copy(),
+            Action::NextLink => ctx.app.focus_next_link(),
+            Action::PreviousLink => ctx.app.focus_previous_link(),
+            Action::ScrollUp => ctx.app.scroll_detail(-1),
+            Action::ScrollDown => ctx.app.scroll_detail(1),
+            Action::PageUp => ctx.app.page_detail(-1),
+            Action::PageDown => ctx.app.page_detail(1),
+            Action::First => ctx.app.select_first(),
+            Action::Last => ctx.app.select_last(),
+            Action::Shell => return Transition::Push(Box::new(ShellScene)),
+            Action::Quotes => {
+                return Transition::Push(Box::new(QuotesScene::new(ctx.app.quote())));
+            }
+            Action::Find => {
+                return Transition::Push(Box::new(FinderScene::new(ctx.app.quote())));
+            }
+            Action::NextTheme => ctx.theme = ctx.theme.next(),
+            Action::NextMotion => ctx.motion = ctx.motion.next(),
+            Action::NextKeymap => ctx.keymap = ctx.keymap.next(),
+            Action::Help => return Transition::Push(Box::new(HelpScene)),
+            Action::Intro => return Transition::Pop,
+            Action::Quit => return Transition::Push(Box::new(GoodbyeScene::new(ctx.motion))),
+        }
+        Transition::Stay
+    }
+}
+
+impl Scene for ListScene {
+    fn on_key_event(&mut self, ctx: &mut Context, event: &KeyEvent) -> Transition {
+        ctx.keymap
+            .action(event)
+            .map_or(Transition::Stay, |action| Self::on_action(ctx, action))
+    }
+
+    fn on_mouse_event(&mut self, ctx: &mut Context, event: MouseEvent) -> Transition {
+        ctx.app.on_mouse_event(&event);
+        Transition::Stay
+    }
+
+    fn render(&mut self, f: &mut Frame<'_>, ctx: &mut Context) {
+        clear(f, &ctx.theme);
+        let vertical = Layout::vertical([Constraint::Percentage(90)]).flex(Flex::Center);
+        let horizontal = Layout::horizontal([Constraint::Percentage(100)]).flex(Flex::Center);
+        let [area] = vertical.areas(f.area());
+        let [area] = horizontal.areas(area);
+        ctx.app
+            .render(area, f.buffer_mut(), &ctx.theme, &ctx.keymap);
+    }
+
+    fn enter_effect(&self, motion: MotionPolicy) -> Option<Effect> {
+        Some(motion.menu_effect())
+    }
+
+    fn screen_reader_view(&self, ctx: &Context) -> Option<View> {
+        Some(View {
+            scene: format!(
+                "Topics. {}.",
+                ctx.keymap.hint(&[
+                    (&[Action::Up, Action::Down], "move"),
+                    (&[Action::ToggleStatus], "mark the selected topic as read"),
+                    (&[Action::Shell], "open the shell"),
+                    (&[Action::Help], "list every key"),
+                ])
+            )
+            .into(),
+            topics: ctx.app.topics(),
+            selected: ctx
+                .app
+                .selected_topic()
+                .and_then(|selected| Topic::registry().position(|topic| topic == selected)),
+            detail: markdown::to_plain_lines(ctx.app.description()),
+            printed: 0,
+            link: ctx
+                .app
+                .focused_link()
+                .map(|link| format!("{}, {}", link.label, link.kind.as_str())),
+        })
+    }
+
+    fn toast<'a>(&'a self, ctx: &'a Context) -> Option<&'a str> {
+        ctx.app.toast()
+    }
+}
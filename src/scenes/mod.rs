@@ -0,0 +1,129 @@
+//! Scenes of the website, stacked on top of each other.
+//!
+//! The scene on top of the stack gets the input and tells the state what comes next with a
+//! [`Transition`]: another scene pushed over it, back to the scene below, or starting over from
+//! a single scene. Popups are overlays, the scene below them is still rendered. A scene coming on
+//! top may play an effect over the frame, built from the motion policy like every other effect.
+
+mod finder;
+mod goodbye;
+mod help;
+mod intro;
+mod list;
+mod quotes;
+mod shell;
+
+pub use finder::FinderScene;
+pub use goodbye::GoodbyeScene;
+pub use help::HelpScene;
+pub use intro::IntroScene;
+pub use list::ListScene;
+pub use quotes::QuotesScene;
+pub use shell::ShellScene;
+
+use crate::keymap::Keymap;
+use crate::models::app::App;
+use crate::models::shell::Shell;
+use crate::motion::MotionPolicy;
+use crate::pointer::MouseEvent;
+use crate::screen_reader::View;
+use crate::theme::Theme;
+use ratzilla::event::KeyEvent;
+use ratzilla::ratatui::Frame;
+use ratzilla::ratatui::prelude::{Stylize, Widget};
+use ratzilla::ratatui::widgets::{Block, Clear};
+use std::any::Any;
+use tachyonfx::Effect;
+
+/// What every scene shares, it outlives the scenes.
+#[derive(Default)]
+pub struct Context {
+    pub app: App,
+    /// Kept while the shell is closed, its history and scrollback stay
+    pub shell: Shell,
+    pub theme: Theme,
+    pub motion: MotionPolicy,
+    pub keymap: Keymap,
+    /// Set once the goodbye scene is over, until a frontend takes it
+    pub exited: bool,
+}
+
+/// What the scene on top asks for after an event or a frame.
+pub enum Transition {
+    /// Nothing changes
+    Stay,
+    /// Show a scene over the current one
+    Push(Box<dyn Scene>),
+    /// Go back to the scene below, the bottom scene is never popped
+    Pop,
+    /// Leave every scene for this one
+    Reset(Box<dyn Scene>),
+}
+
+/// Access to the concrete type of a scene, to ask it what only it knows.
+pub trait AsAny {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A screen of the website, e.g. the intro, the topic list or the shell.
+pub trait Scene: AsAny {
+    /// Called when the scene is pushed on the stack.
+    fn on_enter(&mut self, _ctx: &mut Context) {}
+
+    /// Called when the scene leaves the stack.
+    fn on_exit(&mut self, _ctx: &mut Context) {}
+
+    /// Handle a key pressed while the scene is on top.
+    fn on_key_event(&mut self, ctx: &mut Context, event: &KeyEvent) -> Transition;
+
+    /// Handle a mouse or touch event while the scene is on top.
+    fn on_mouse_event(&mut self, _ctx: &mut Context, _event: MouseEvent) -> Transition {
+        Transition::Stay
+    }
+
+    /// Advance the scene by a frame, before it is rendered, while it is on top.
+    fn update(&mut self, _ctx: &mut Context) -> Transition {
+        Transition::Stay
+    }
+
+    /// Render the scene over the whole frame.
+    fn render(&mut self, f: &mut Frame<'_>, ctx: &mut Context);
+
+    /// Whether the scene below is rendered first, e.g. for popups.
+    fn is_overlay(&self) -> bool {
+        false
+    }
+
+    /// Effect played over the frame once the scene is pushed.
+    fn enter_effect(&self, _motion: MotionPolicy) -> Option<Effect> {
+        None
+    }
+
+    /// Rebuild the effects of the scene from `motion`, they start over.
+    fn restart_effects(&mut self, _motion: MotionPolicy) {}
+
+    /// What screen readers are told of the scene, `None` keeps what they know of the scene
+    /// below.
+    fn screen_reader_view(&self, _ctx: &Context) -> Option<View> {
+        None
+    }
+
+    /// Message of the toast shown over the scene, if any.
+    fn toast<'a>(&'a self, _ctx: &'a Context) -> Option<&'a str> {
+        None
+    }
+}
+
+/// Clear the previous frame with the screen background of `theme`.
+fn clear(f: &mut Frame<'_>, theme: &Theme) {
+    Clear.render(f.area(), f.buffer_mut());
+    Block::new()
+        .bg(theme.screen)
+        .render(f.area(), f.buffer_mut());
+}
@@ -0,0 +1,82 @@
+//! Quote book scene, where visitors browse the quotes.
+
+use super::{Context, Scene, Transition, clear};
+use crate::models::quote::Quote;
+use crate::models::quote_book::QuoteBook;
+use crate::motion::MotionPolicy;
+use crate::screen_reader::View;
+use ratzilla::event::KeyEvent;
+use ratzilla::ratatui::Frame;
+use tachyonfx::{CenteredShrink, Effect};
+
+/// Shows a quote book, quote topics show the quote read last once it is closed.
+pub struct QuotesScene {
+    book: QuoteBook,
+}
+
+impl QuotesScene {
+    /// Book opened on `quote`, or on the first quote.
+    #[must_use]
+    pub fn new(quote: Option<&Quote>) -> Self {
+        Self {
+            book: QuoteBook::new(quote),
+        }
+    }
+
+    /// Quote shown, `None` when no quote matches the filter.
+    #[must_use]
+    pub fn current(&self) -> Option<&'static Quote> {
+        self.book.current()
+    }
+}
+
+impl Scene for QuotesScene {
+    fn on_exit(&mut self, ctx: &mut Context) {
+        if let Some(quote) = self.book.current() {
+            ctx.app.restore_quote(Some(quote));
+        }
+    }
+
+    fn on_key_event(&mut self, _ctx: &mut Context, event: &KeyEvent) -> Transition {
+        self.book.on_key_event(event);
+        if self.book.take_exit_request() {
+            Transition::Pop
+        } else {
+            Transition::Stay
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame<'_>, ctx: &mut Context) {
+        clear(f, &ctx.theme);
+        let area = f
+            .area()
+            .inner_centered(f.area().width.min(90), f.area().height.min(20));
+        self.book.render(area, f.buffer_mut(), &ctx.theme);
+    }
+
+    fn enter_effect(&self, motion: MotionPolicy) -> Option<Effect> {
+        Some(motion.scene_effect())
+    }
+
+    fn screen_reader_view(&self, _ctx: &Context) -> Option<View> {
+        Some(View {
+            scene: "Quotes. Use the left and right arrows to browse, slash to filter by author \
+                or tag, s to copy the quote with a link to it, Escape goes back to the topics."
+                .into(),
+            detail: self.book.current().map_or_else(
+                || vec!["No quote by this author or with this tag".to_string()],
+                |quote| {
+                    vec![
+                        quote.to_string(),
+                        format!("Tags: {}", quote.tags.join(", ")),
+                    ]
+                },
+            ),
+            ..View::default()
+        })
+    }
+
+    fn toast<'a>(&'a self, _ctx: &'a Context) -> Option<&'a str> {
+        self.book.toast()
+    }
+}
@@ -0,0 +1,53 @@
+//! Shell scene, where visitors type commands.
+
+use super::{Context, Scene, Transition, clear};
+use crate::motion::MotionPolicy;
+use crate::screen_reader::View;
+use ratzilla::event::{KeyCode, KeyEvent};
+use ratzilla::ratatui::Frame;
+use ratzilla::ratatui::layout::{Constraint, Flex, Layout};
+use tachyonfx::Effect;
+
+/// Shows the shell of the context, kept between visits of the scene.
+pub struct ShellScene;
+
+impl Scene for ShellScene {
+    fn on_enter(&mut self, ctx: &mut Context) {
+        ctx.shell.set_quote(ctx.app.quote());
+    }
+
+    /// Keys typed in the shell are text, only Esc and `exit` go back to the topic list.
+    fn on_key_event(&mut self, ctx: &mut Context, event: &KeyEvent) -> Transition {
+        if event.code == KeyCode::Esc {
+            return Transition::Pop;
+        }
+        ctx.shell.on_key_event(event);
+        if ctx.shell.take_exit_request() {
+            Transition::Pop
+        } else {
+            Transition::Stay
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame<'_>, ctx: &mut Context) {
+        clear(f, &ctx.theme);
+        let vertical = Layout::vertical([Constraint::Percentage(90)]).flex(Flex::Center);
+        let [area] = vertical.areas(f.area());
+        ctx.shell.render(area, f.buffer_mut(), &ctx.theme);
+    }
+
+    fn enter_effect(&self, motion: MotionPolicy) -> Option<Effect> {
+        Some(motion.scene_effect())
+    }
+
+    fn screen_reader_view(&self, ctx: &Context) -> Option<View> {
+        Some(View {
+            scene: "Shell. Type help to list the available commands, Escape goes back to the \
+                topics."
+                .into(),
+            detail: ctx.shell.transcript(),
+            printed: ctx.shell.printed(),
+            ..View::default()
+        })
+    }
+}
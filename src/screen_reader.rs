@@ -0,0 +1,173 @@
+//! Plain HTML mirror of the terminal, for screen readers.
+//!
+//! Backends draw on a canvas or in styled cells, which assistive technology cannot make sense
+//! of. A visually hidden region of the page repeats the current scene instead: the topics as a
+//! list, the selected topic as text, and a live region announcing selection and status changes.
+//! It lives outside the terminal host, so it survives backend swaps.
+
+use crate::models::status::Status;
+use crate::models::topic::Topic;
+use ratzilla::web_sys;
+use ratzilla::web_sys::wasm_bindgen::JsValue;
+use std::borrow::Cow;
+
+/// Id of the element holding the mirror
+pub const SCREEN_READER_ID: &str = "screen-reader";
+
+/// Hide the mirror from sight but not from assistive technology
+const VISUALLY_HIDDEN: &str = "position: absolute; width: 1px; height: 1px; margin: -1px; \
+    padding: 0; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: pre-line; border: 0;";
+
+/// What the mirror shows of a scene.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct View {
+    /// Sentence describing the scene, announced when entering it
+    pub scene: Cow<'static, str>,
+    /// Every topic with its status, empty outside of the topic list
+    pub topics: Vec<(Topic, Status)>,
+    /// Index of the selected topic
+    pub selected: Option<usize>,
+    /// Text of the selected topic, or the shell output
+    pub detail: Vec<String>,
+    /// Lines printed by the shell so far, only the new ones are announced
+    pub printed: usize,
+    /// Link of the selected topic focused with Tab, e.g. `Work, mail`
+    pub link: Option<String>,
+}
+
+/// What to announce when the view goes from `previous` to `current`, if anything changed.
+#[must_use]
+pub fn announcement(previous: &View, current: &View) -> Option<String> {
+    if current.scene != previous.scene {
+        return Some(current.scene.to_string());
+    }
+    if current.selected != previous.selected {
+        return Some(current.selected.map_or_else(
+            || "Nothing selected".to_string(),
+            |index| {
+                let (topic, status) = current.topics[index];
+                format!(
+                    "{topic}, {}, {} of {}. {}",
+                    status_name(status),
+                    index + 1,
+                    current.topics.len(),
+                    current.detail.join("\n")
+                )
+            },
+        ));
+    }
+    let status_change = current.selected.and_then(|index| {
+        let (topic, status) = *current.topics.get(index)?;
+        let (_, before) = *previous.topics.get(index)?;
+        (status != before).then(|| format!("{topic} marked as {}", status_name(status)))
+    });
+    if status_change.is_some() {
+        return status_change;
+    }
+    if current.link != previous.link {
+        if let Some(link) = &current.link {
+            return Some(format!("{link} link"));
+        }
+    }
+    let new_lines = current.printed.saturating_sub(previous.printed);
+    if new_lines > 0 {
+        let start = current.detail.len().saturating_sub(new_lines);
+        return Some(current.detail[start..].join("\n"));
+    }
+    // Scenes without topics nor shell output, such as the quotes, are read whole
+    let replaced = current.topics.is_empty() && current.printed == 0;
+    (replaced && current.detail != previous.detail).then(|| current.detail.join("\n"))
+}
+
+const fn status_name(status: Status) -> &'static str {
+    match status {
+        Status::Todo => "to read",
+        Status::Completed => "read",
+    }
+}
+
+/// Keeps the hidden region of the page in sync with the current scene.
+#[derive(Default)]
+pub struct ScreenReader {
+    elements: Option<Elements>,
+    /// View shown by the elements
+    shown: View,
+}
+
+struct Elements {
+    list: web_sys::Element,
+    detail: web_sys::Element,
+    live: web_sys::Element,
+}
+
+impl ScreenReader {
+    /// Add the hidden region to the page, without a page the mirror does nothing.
+    #[must_use]
+    pub fn attach() -> Self {
+        let elements = create_elements().inspect_err(web_sys::console::warn_1);
+        Self {
+            elements: elements.ok(),
+            shown: View::default(),
+        }
+    }
+
+    /// Update the region when the view changed since the last call.
+    pub fn sync(&mut self, view: View) {
+        if view == self.shown {
+            return;
+        }
+        if let Some(elements) = &self.elements {
+            if let Err(err) = elements.update(&self.shown, &view) {
+                web_sys::console::warn_1(&err);
+            }
+        }
+        self.shown = view;
+    }
+}
+
+impl Elements {
+    fn update(&self, shown: &View, view: &View) -> Result<(), JsValue> {
+        if view.topics != shown.topics || view.selected != shown.selected {
+            let document = self.list.owner_document().ok_or("No document")?;
+            self.list.set_inner_html("");
+            for (index, (topic, status)) in view.topics.iter().enumerate() {
+                let item = document.create_element("li")?;
+                item.set_text_content(Some(&format!("{topic}, {}", status_name(*status))));
+                if view.selected == Some(index) {
+                    item.set_attribute("aria-current", "true")?;
+                }
+                self.list.append_child(&item)?;
+            }
+        }
+        if view.detail != shown.detail {
+            self.detail.set_text_content(Some(&view.detail.join("\n")));
+        }
+        if let Some(message) = announcement(shown, view) {
+            self.live.set_text_content(Some(&message));
+        }
+        Ok(())
+    }
+}
+
+fn create_elements() -> Result<Elements, JsValue> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("No document")?;
+    let root = document.create_element("div")?;
+    root.set_id(SCREEN_READER_ID);
+    root.set_attribute("style", VISUALLY_HIDDEN)?;
+
+    let list = document.create_element("ul")?;
+    list.set_attribute("aria-label", "Topics")?;
+    let detail = document.create_element("section")?;
+    detail.set_attribute("aria-label", "Selected topic")?;
+    let live = document.create_element("div")?;
+    live.set_attribute("role", "status")?;
+    live.set_attribute("aria-live", "polite")?;
+    live.set_attribute("aria-atomic", "true")?;
+    for element in [&live, &list, &detail] {
+        root.append_child(element)?;
+    }
+    document.body().ok_or("No body")?.append_child(&root)?;
+    Ok(Elements { list, detail, live })
+}
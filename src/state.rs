@@ -0,0 +1,318 @@
+use crate::keymap::Keymap;
+use crate::models::quote::{Quote, QuotePicker};
+use crate::models::topic::{Link, Topic};
+use crate::motion::MotionPolicy;
+use crate::pointer::MouseEvent;
+use crate::scenes::{Context, IntroScene, ListScene, QuotesScene, Scene, Transition};
+use crate::screen_reader::View;
+use crate::storage::Visit;
+use crate::theme::Theme;
+use ratzilla::event::KeyEvent;
+use ratzilla::ratatui::Frame;
+use ratzilla::ratatui::layout::Size;
+use tachyonfx::{Duration, Effect, EffectRenderer, Shader};
+
+/// Time a frame moves the effect of a scene coming on top by
+const TRANSITION_FRAME: Duration = Duration::from_millis(100);
+
+/// Whole website state, shared by every frontend (browser, native terminal).
+pub struct State {
+    /// Scenes on top of each other, the last one is shown and gets the input
+    scenes: Vec<Box<dyn Scene>>,
+    /// Effect of the scene that came on top last, until it is done
+    transition: Option<Effect>,
+    /// What every scene shares
+    context: Context,
+    /// Size of the last rendered frame, in cells
+    frame_size: Size,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let context = Context::default();
+        Self {
+            scenes: vec![Box::new(IntroScene::new(context.motion))],
+            transition: None,
+            context,
+            frame_size: Size::default(),
+        }
+    }
+}
+
+/// Render the current scene of the given state.
+///
+/// The scene on top is advanced by a frame first, then rendered over the scenes it overlays.
+pub fn ui(f: &mut Frame<'_>, state: &mut State) {
+    state.frame_size = f.area().as_size();
+    if let Some(scene) = state.scenes.last_mut() {
+        let transition = scene.update(&mut state.context);
+        state.apply(transition);
+    }
+    let base = state.base();
+    for scene in &mut state.scenes[base..] {
+        scene.render(f, &mut state.context);
+    }
+    if let Some(effect) = &mut state.transition {
+        f.render_effect(effect, f.area(), TRANSITION_FRAME);
+        if effect.done() {
+            state.transition = None;
+        }
+    }
+}
+
+impl State {
+    /// Create a state whose scenes are rendered without any effect.
+    ///
+    /// Effects are time based, without them every render of the same state is identical,
+    /// which is what headless rendering and snapshot tests need.
+    #[must_use]
+    pub fn without_effects() -> Self {
+        let mut state = Self::default();
+        state.set_motion(MotionPolicy::None);
+        state
+    }
+
+    /// Topic selected in the list, if any.
+    #[must_use]
+    pub fn selected_topic(&self) -> Option<Topic> {
+        self.context.app.selected_topic()
+    }
+
+    /// Link of the selected topic focused with Tab, if any.
+    #[must_use]
+    pub fn focused_link(&self) -> Option<Link> {
+        self.context.app.focused_link()
+    }
+
+    /// Draw quotes with `picker` from now on, starting with a new one.
+    pub fn set_quotes(&mut self, picker: QuotePicker) {
+        self.context.app.set_quotes(picker);
+    }
+
+    /// Message of the toast shown over the current scene, if any.
+    #[must_use]
+    pub fn toast(&self) -> Option<&str> {
+        self.scenes[self.base()].toast(&self.context)
+    }
+
+    /// Select `topic` in the list, a linked topic skips the intro.
+    ///
+    /// Links, the browser history and restored visits select this way, the quote shown stays.
+    pub fn select_topic(&mut self, topic: Option<Topic>) {
+        self.context.app.select_topic(topic);
+        if topic.is_some() {
+            self.show_list();
+        }
+    }
+
+    /// Palette every scene is rendered with.
+    #[must_use]
+    pub const fn theme(&self) -> Theme {
+        self.context.theme
+    }
+
+    /// Render every scene with `theme` from now on.
+    pub const fn set_theme(&mut self, theme: Theme) {
+        self.context.theme = theme;
+    }
+
+    /// Motion policy the effects are built from.
+    #[must_use]
+    pub const fn motion(&self) -> MotionPolicy {
+        self.context.motion
+    }
+
+    /// Rebuild the effects of every scene from `motion`, they start over.
+    pub fn set_motion(&mut self, motion: MotionPolicy) {
+        self.context.motion = motion;
+        self.restart_effects();
+    }
+
+    /// Whether the visitor quit since the last call.
+    ///
+    /// The website is back on the intro by then, native frontends close the session instead.
+    pub const fn take_exited(&mut self) -> bool {
+        std::mem::replace(&mut self.context.exited, false)
+    }
+
+    /// Key bindings of the topic list.
+    #[must_use]
+    pub const fn keymap(&self) -> Keymap {
+        self.context.keymap
+    }
+
+    /// Bind the keys of `keymap` from now on.
+    pub const fn set_keymap(&mut self, keymap: Keymap) {
+        self.context.keymap = keymap;
+    }
+
+    /// Size of the last rendered frame, in cells.
+    #[must_use]
+    pub const fn frame_size(&self) -> Size {
+        self.frame_size
+    }
+
+    /// Dispatch a mouse or touch event to the current scene.
+    pub fn on_mouse_event(&mut self, event: &MouseEvent) {
+        if let Some(scene) = self.scenes.last_mut() {
+            let transition = scene.on_mouse_event(&mut self.context, *event);
+            self.apply(transition);
+        }
+    }
+
+    /// What to remember of the current visit.
+    #[must_use]
+    pub fn visit(&self) -> Visit {
+        Visit {
+            completed: self.context.app.completed_topics(),
+            selected: self.selected_topic(),
+            last_quote: self.context.app.quote(),
+        }
+    }
+
+    /// What screen readers are told of the current scene, popups keep the view of the scene
+    /// below.
+    #[must_use]
+    pub fn screen_reader_view(&self) -> View {
+        self.scenes
+            .iter()
+            .rev()
+            .find_map(|scene| scene.screen_reader_view(&self.context))
+            .unwrap_or_default()
+    }
+
+    /// Pick up a previous visit where it was left.
+    pub fn restore_visit(&mut self, visit: &Visit) {
+        self.context.app.set_completed_topics(&visit.completed);
+        if visit.last_quote.is_some() {
+            self.context.app.restore_quote(visit.last_quote);
+        }
+        self.select_topic(visit.selected);
+    }
+
+    /// Dispatch a key event to the current scene.
+    ///
+    /// This is the input layer shared by every frontend: the browser forwards ratzilla's
+    /// events as they are, native frontends translate their own events into a [`KeyEvent`].
+    /// A scene may switch the motion policy, e.g. `m` in the topic list, every effect follows.
+    pub fn on_key_event(&mut self, event: &KeyEvent) {
+        let motion = self.context.motion;
+        if let Some(scene) = self.scenes.last_mut() {
+            let transition = scene.on_key_event(&mut self.context, event);
+            self.apply(transition);
+        }
+        if self.context.motion != motion {
+            self.restart_effects();
+        }
+    }
+
+    /// Open the quote book on `quote`, or on the first quote, over the topic list.
+    pub fn open_quote(&mut self, quote: Option<&Quote>) {
+        self.show_list();
+        self.push(Box::new(QuotesScene::new(quote)));
+    }
+
+    /// Quote shown by the quote book, if open on a quote.
+    #[must_use]
+    pub fn book_quote(&self) -> Option<&'static Quote> {
+        self.top::<QuotesScene>().and_then(QuotesScene::current)
+    }
+
+    /// Render the scene of type `T`, whatever is on top of the stack.
+    ///
+    /// The last `T` of the stack is rendered with the popups over it, or else a scene made
+    /// with `scene` and dropped right after: the stack is left as it is.
+    pub(crate) fn render_scene<T: Scene + 'static>(
+        &mut self,
+        f: &mut Frame<'_>,
+        scene: impl FnOnce(&Context) -> T,
+    ) {
+        self.frame_size = f.area().as_size();
+        let stacked = self
+            .scenes
+            .iter()
+            .rposition(|stacked| stacked.as_ref().as_any().is::<T>());
+        if let Some(index) = stacked {
+            let overlays = self.scenes[index + 1..]
+                .iter()
+                .take_while(|scene| scene.is_overlay())
+                .count();
+            for scene in &mut self.scenes[index..=index + overlays] {
+                scene.render(f, &mut self.context);
+            }
+        } else {
+            scene(&self.context).render(f, &mut self.context);
+        }
+    }
+
+    /// Number of scenes on the stack, the scene on top and every one below it.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.scenes.len()
+    }
+
+    /// Scene on top of the stack, if it is a `T`
+    fn top<T: Scene + 'static>(&self) -> Option<&T> {
+        self.scenes.last()?.as_ref().as_any().downcast_ref()
+    }
+
+    /// Index of the first scene to render, the scene on top and every scene it overlays
+    fn base(&self) -> usize {
+        self.scenes
+            .iter()
+            .rposition(|scene| !scene.is_overlay())
+            .unwrap_or(0)
+    }
+
+    /// Close every scene over the topic list, or open it over the intro
+    fn show_list(&mut self) {
+        while self.scenes.len() > 1 && self.top::<ListScene>().is_none() {
+            self.pop();
+        }
+        if self.top::<ListScene>().is_none() {
+            self.push(Box::new(ListScene));
+        }
+    }
+
+    fn apply(&mut self, transition: Transition) {
+        match transition {
+            Transition::Stay => {}
+            Transition::Push(scene) => self.push(scene),
+            Transition::Pop => self.pop(),
+            Transition::Reset(scene) => {
+                while let Some(mut previous) = self.scenes.pop() {
+                    previous.on_exit(&mut self.context);
+                }
+                self.push(scene);
+            }
+        }
+    }
+
+    /// Show `scene` over the current one, with its effect
+    fn push(&mut self, mut scene: Box<dyn Scene>) {
+        scene.on_enter(&mut self.context);
+        self.transition = scene.enter_effect(self.context.motion);
+        self.scenes.push(scene);
+    }
+
+    /// Go back to the scene below, the bottom scene stays
+    fn pop(&mut self) {
+        if self.scenes.len() > 1 {
+            if let Some(mut scene) = self.scenes.pop() {
+                scene.on_exit(&mut self.context);
+            }
+        }
+    }
+
+    /// Rebuild every effect from the motion policy of the context
+    fn restart_effects(&mut self) {
+        let motion = self.context.motion;
+        for scene in &mut self.scenes {
+            scene.restart_effects(motion);
+        }
+        self.transition = self
+            .scenes
+            .last()
+            .and_then(|scene| scene.enter_effect(motion));
+    }
+}
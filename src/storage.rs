@@ -0,0 +1,110 @@
+//! Remember returning visitors through the browser `localStorage`.
+//!
+//! Keys are prefixed with a version, a change of format bumps it and the previous values are
+//! ignored. Storage can be disabled or full, every failure leaves the website working as if
+//! the visitor came for the first time.
+
+use crate::backend::BackendType;
+use crate::models::quote::Quote;
+use crate::models::topic::Topic;
+use ratzilla::web_sys;
+
+/// Version of the stored format, part of every key
+const VERSION: u32 = 1;
+
+const COMPLETED_KEY: &str = "completed";
+const SELECTED_KEY: &str = "selected";
+const LAST_QUOTE_KEY: &str = "last-quote";
+const BACKEND_KEY: &str = "backend";
+
+/// What is kept of a visit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Visit {
+    pub completed: Vec<Topic>,
+    pub selected: Option<Topic>,
+    pub last_quote: Option<&'static Quote>,
+}
+
+/// Access to the local storage, if the browser allows it.
+pub struct Storage {
+    storage: Option<web_sys::Storage>,
+    /// Last visit written, to only write on changes
+    saved: Option<Visit>,
+}
+
+impl Storage {
+    /// Open the local storage, an unavailable storage is not an error.
+    #[must_use]
+    pub fn open() -> Self {
+        Self {
+            storage: local_storage(),
+            saved: None,
+        }
+    }
+
+    /// Load the previous visit, if any.
+    ///
+    /// Topics and quotes that no longer exist are dropped.
+    pub fn load(&mut self) -> Option<Visit> {
+        let completed = self.get(COMPLETED_KEY)?;
+        let visit = Visit {
+            completed: completed.split(',').filter_map(Topic::from_id).collect(),
+            selected: self.get(SELECTED_KEY).and_then(|id| Topic::from_id(&id)),
+            last_quote: self.get(LAST_QUOTE_KEY).and_then(|id| Quote::from_id(&id)),
+        };
+        self.saved = Some(visit.clone());
+        Some(visit)
+    }
+
+    /// Write the visit, unless it did not change since the last save.
+    pub fn save(&mut self, visit: Visit) {
+        if self.saved.as_ref() == Some(&visit) {
+            return;
+        }
+        let completed = visit
+            .completed
+            .iter()
+            .map(Topic::id)
+            .collect::<Vec<_>>()
+            .join(",");
+        self.set(COMPLETED_KEY, &completed);
+        self.set(SELECTED_KEY, visit.selected.map_or("", |topic| topic.id()));
+        self.set(
+            LAST_QUOTE_KEY,
+            visit.last_quote.map_or("", |quote| quote.id),
+        );
+        self.saved = Some(visit);
+    }
+
+    /// Backend chosen by the visitor on a previous visit.
+    #[must_use]
+    pub fn preferred_backend(&self) -> Option<BackendType> {
+        self.get(BACKEND_KEY)
+            .and_then(|backend| BackendType::try_from(backend).ok())
+    }
+
+    fn get(&self, name: &str) -> Option<String> {
+        self.storage.as_ref()?.get_item(&key(name)).ok().flatten()
+    }
+
+    fn set(&self, name: &str, value: &str) {
+        if let Some(storage) = &self.storage {
+            // A full or read-only storage only means the visit is not remembered
+            let _ = storage.set_item(&key(name), value);
+        }
+    }
+}
+
+/// Remember the backend chosen by the visitor for the next visits.
+pub fn save_preferred_backend(backend: BackendType) {
+    Storage::open().set(BACKEND_KEY, backend.as_str());
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    // Accessing the storage throws when it is disabled, e.g. for sandboxed frames
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+fn key(name: &str) -> String {
+    format!("website.v{VERSION}.{name}")
+}
@@ -0,0 +1,208 @@
+//! Colour palettes of the website, switchable at runtime.
+//!
+//! The palette is picked from the `?theme=` URL parameter, then from the browser
+//! `prefers-color-scheme`, and `t` cycles through every palette.
+
+use crate::utils::{matches_media, url_param};
+use ratzilla::ratatui::style::{
+    Color, Modifier, Style,
+    palette::tailwind::{AMBER, CYAN, FUCHSIA, GRAY, GREEN, PINK, RED, SLATE},
+};
+
+/// Colours and styles used to render every scene.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Name used by the `?theme=` URL parameter
+    pub name: &'static str,
+    /// Background of the whole screen
+    pub screen: Color,
+    /// Background of the list and detail panes
+    pub panel: Color,
+    /// Title bar of the panes
+    pub header: Style,
+    /// Highlight of the selected list item
+    pub selected: Style,
+    /// Topics still to read, list bullets and command names
+    pub text: Color,
+    /// Topic descriptions and command output
+    pub data: Color,
+    /// Topics already read
+    pub completed: Color,
+    /// Website title and key help
+    pub title: Color,
+    /// Website name on the intro and error messages
+    pub accent: Color,
+    pub heading: Style,
+    pub code: Style,
+    pub link: Style,
+    /// Destination shown after a link text
+    pub link_url: Color,
+}
+
+pub const TAILWIND_DARK: Theme = Theme {
+    name: "tailwind",
+    screen: Color::Reset,
+    panel: SLATE.c950,
+    header: Style::new().fg(FUCHSIA.c200).bg(GRAY.c800),
+    selected: Style::new()
+        .bg(CYAN.c900)
+        .add_modifier(Modifier::CROSSED_OUT),
+    text: GREEN.c300,
+    data: AMBER.c300,
+    completed: PINK.c950,
+    title: Color::LightMagenta,
+    accent: Color::LightRed,
+    heading: Style::new()
+        .fg(FUCHSIA.c200)
+        .add_modifier(Modifier::BOLD.union(Modifier::UNDERLINED)),
+    code: Style::new().fg(GREEN.c300).bg(GRAY.c800),
+    link: Style::new()
+        .fg(CYAN.c300)
+        .add_modifier(Modifier::UNDERLINED),
+    link_url: SLATE.c400,
+};
+
+pub const SOLARIZED: Theme = Theme {
+    name: "solarized",
+    screen: Color::from_u32(0x0000_2b36),
+    panel: Color::from_u32(0x0000_2b36),
+    header: Style::new()
+        .fg(Color::from_u32(0x006c_71c4))
+        .bg(Color::from_u32(0x0007_3642)),
+    selected: Style::new()
+        .bg(Color::from_u32(0x0007_3642))
+        .add_modifier(Modifier::CROSSED_OUT),
+    text: Color::from_u32(0x0085_9900),
+    data: Color::from_u32(0x00b5_8900),
+    completed: Color::from_u32(0x0058_6e75),
+    title: Color::from_u32(0x006c_71c4),
+    accent: Color::from_u32(0x00dc_322f),
+    heading: Style::new()
+        .fg(Color::from_u32(0x00d3_3682))
+        .add_modifier(Modifier::BOLD.union(Modifier::UNDERLINED)),
+    code: Style::new()
+        .fg(Color::from_u32(0x002a_a198))
+        .bg(Color::from_u32(0x0007_3642)),
+    link: Style::new()
+        .fg(Color::from_u32(0x0026_8bd2))
+        .add_modifier(Modifier::UNDERLINED),
+    link_url: Color::from_u32(0x0093_a1a1),
+};
+
+pub const GRUVBOX: Theme = Theme {
+    name: "gruvbox",
+    screen: Color::from_u32(0x0028_2828),
+    panel: Color::from_u32(0x001d_2021),
+    header: Style::new()
+        .fg(Color::from_u32(0x00d3_869b))
+        .bg(Color::from_u32(0x003c_3836)),
+    selected: Style::new()
+        .bg(Color::from_u32(0x0045_8588))
+        .add_modifier(Modifier::CROSSED_OUT),
+    text: Color::from_u32(0x00b8_bb26),
+    data: Color::from_u32(0x00fa_bd2f),
+    completed: Color::from_u32(0x0092_8374),
+    title: Color::from_u32(0x00d3_869b),
+    accent: Color::from_u32(0x00fb_4934),
+    heading: Style::new()
+        .fg(Color::from_u32(0x00d3_869b))
+        .add_modifier(Modifier::BOLD.union(Modifier::UNDERLINED)),
+    code: Style::new()
+        .fg(Color::from_u32(0x008e_c07c))
+        .bg(Color::from_u32(0x003c_3836)),
+    link: Style::new()
+        .fg(Color::from_u32(0x0083_a598))
+        .add_modifier(Modifier::UNDERLINED),
+    link_url: Color::from_u32(0x00a8_9984),
+};
+
+/// Pure colours only, for low vision visitors
+pub const HIGH_CONTRAST: Theme = Theme {
+    name: "high-contrast",
+    screen: Color::Black,
+    panel: Color::Black,
+    header: Style::new()
+        .fg(Color::Black)
+        .bg(Color::White)
+        .add_modifier(Modifier::BOLD),
+    selected: Style::new()
+        .fg(Color::Black)
+        .bg(Color::Yellow)
+        .add_modifier(Modifier::BOLD),
+    text: Color::White,
+    data: Color::LightYellow,
+    completed: Color::LightCyan,
+    title: Color::White,
+    accent: Color::LightRed,
+    heading: Style::new()
+        .fg(Color::White)
+        .add_modifier(Modifier::BOLD.union(Modifier::UNDERLINED)),
+    code: Style::new().fg(Color::Black).bg(Color::White),
+    link: Style::new()
+        .fg(Color::LightCyan)
+        .add_modifier(Modifier::UNDERLINED),
+    link_url: Color::White,
+};
+
+pub const LIGHT: Theme = Theme {
+    name: "light",
+    screen: SLATE.c50,
+    panel: SLATE.c100,
+    header: Style::new().fg(FUCHSIA.c800).bg(SLATE.c200),
+    selected: Style::new()
+        .bg(CYAN.c200)
+        .add_modifier(Modifier::CROSSED_OUT),
+    text: GREEN.c800,
+    data: AMBER.c900,
+    completed: SLATE.c400,
+    title: FUCHSIA.c700,
+    accent: RED.c600,
+    heading: Style::new()
+        .fg(FUCHSIA.c700)
+        .add_modifier(Modifier::BOLD.union(Modifier::UNDERLINED)),
+    code: Style::new().fg(GREEN.c800).bg(SLATE.c200),
+    link: Style::new()
+        .fg(CYAN.c700)
+        .add_modifier(Modifier::UNDERLINED),
+    link_url: SLATE.c500,
+};
+
+/// Every palette, in the order `t` cycles through them
+pub const THEMES: [Theme; 5] = [TAILWIND_DARK, SOLARIZED, GRUVBOX, HIGH_CONTRAST, LIGHT];
+
+impl Default for Theme {
+    fn default() -> Self {
+        TAILWIND_DARK
+    }
+}
+
+impl Theme {
+    /// Find a palette by name, case insensitive.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        THEMES
+            .into_iter()
+            .find(|theme| theme.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The palette after this one, back to the first after the last.
+    #[must_use]
+    pub fn next(&self) -> Self {
+        let index = THEMES.iter().position(|theme| theme == self).unwrap_or(0);
+        THEMES[(index + 1) % THEMES.len()]
+    }
+
+    /// Palette asked by the `?theme=` URL parameter, or the one matching the browser
+    /// `prefers-color-scheme`.
+    #[must_use]
+    pub fn from_browser() -> Self {
+        let asked = url_param("theme").and_then(|name| Self::from_name(&name));
+        asked.unwrap_or_else(|| {
+            if matches_media("(prefers-color-scheme: light)") {
+                LIGHT
+            } else {
+                TAILWIND_DARK
+            }
+        })
+    }
+}
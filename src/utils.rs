@@ -1,6 +1,53 @@
-use crate::backend::BackendType;
+use crate::backend::{BackendType, TERMINAL_HOST_ID, request_backend_switch};
+use crate::storage::save_preferred_backend;
 use ratzilla::web_sys;
 use ratzilla::web_sys::wasm_bindgen::JsValue;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+
+/// Open the link in a new browser tab
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn open_link(url: &str) {
+    let _ = ratzilla::utils::open_url(url, true);
+}
+
+/// Native terminals have no browser to open links in, they are shown in the description instead
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const fn open_link(_url: &str) {}
+
+/// Value of the `name` query parameter of the current URL
+pub(crate) fn url_param(name: &str) -> Option<String> {
+    let href = web_sys::window()?.location().href().ok()?;
+    web_sys::Url::new(&href).ok()?.search_params().get(name)
+}
+
+/// Whether the browser matches the given media query, e.g. `(prefers-color-scheme: light)`
+pub(crate) fn matches_media(query: &str) -> bool {
+    web_sys::window()
+        .and_then(|w| w.match_media(query).ok().flatten())
+        .is_some_and(|query| query.matches())
+}
+
+/// Make sure the terminal host element exists and is empty
+pub(crate) fn prepare_terminal_host() -> Result<(), JsValue> {
+    let window = web_sys::window().ok_or("No window")?;
+    let document = window.document().ok_or("No document")?;
+
+    if let Some(host) = document.get_element_by_id(TERMINAL_HOST_ID) {
+        host.set_inner_html("");
+        return Ok(());
+    }
+
+    let host = document.create_element("div")?;
+    host.set_id(TERMINAL_HOST_ID);
+    // Screen readers read the mirror of the terminal instead, see `screen_reader`
+    host.set_attribute("aria-hidden", "true")?;
+    // The host must not alter the page layout, backends behave as if mounted in the body
+    host.set_attribute("style", "display: contents;")?;
+    let body = document.body().ok_or("No body")?;
+    body.append_child(&host)?;
+    Ok(())
+}
 
 /// Inject HTML footer with backend switching links
 pub(crate) fn inject_backend_footer(current_backend: BackendType) -> Result<(), JsValue> {
@@ -30,39 +77,64 @@ pub(crate) fn inject_backend_footer(current_backend: BackendType) -> Result<(),
     let location = window.location();
     let base_url = location.pathname().unwrap_or_default();
 
-    let backends = [BackendType::Dom, BackendType::Canvas];
-    let mut links = Vec::new();
+    let label = document.create_element("span")?;
+    label.set_attribute("style", "color: #64748b;")?;
+    label.set_text_content(Some("Backend:"));
+    footer.append_child(&label)?;
 
-    for backend in backends {
-        let is_current = backend == current_backend;
-        let style = if is_current {
-            "color: #4ade80; font-weight: bold; text-decoration: none;"
+    let backends = [BackendType::Dom, BackendType::Canvas, BackendType::WebGl2];
+    for (i, backend) in backends.into_iter().enumerate() {
+        if i > 0 {
+            footer.append_child(&document.create_text_node("|"))?;
+        }
+        let link = if backend == current_backend {
+            let span = document.create_element("span")?;
+            span.set_attribute(
+                "style",
+                "color: #4ade80; font-weight: bold; text-decoration: none;",
+            )?;
+            span.set_text_content(Some(&format!("● {backend}")));
+            span
         } else {
-            "color: #94a3b8; text-decoration: none; cursor: pointer;"
+            let href = format!("{base_url}?backend={}", backend.as_str());
+            let anchor = document.create_element("a")?;
+            anchor.set_attribute("href", &href)?;
+            anchor.set_attribute(
+                "style",
+                "color: #94a3b8; text-decoration: none; cursor: pointer;",
+            )?;
+            anchor.set_text_content(Some(backend.as_str()));
+            // Switch live instead of navigating, the href is kept for the reload case
+            let on_click = Closure::<dyn FnMut(_)>::new(move |event: web_sys::Event| {
+                event.prevent_default();
+                if let Some(window) = web_sys::window() {
+                    // Keep the hash, it links the selected topic
+                    let hash = window.location().hash().unwrap_or_default();
+                    if let Ok(history) = window.history() {
+                        let url = format!("{href}{hash}");
+                        let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&url));
+                    }
+                }
+                save_preferred_backend(backend);
+                request_backend_switch(backend);
+            });
+            anchor.add_event_listener_with_callback("click", on_click.as_ref().unchecked_ref())?;
+            on_click.forget();
+            anchor
         };
-
-        let link = if is_current {
-            format!("<span style=\"{style}\">● {backend}</span>")
-        } else {
-            format!(
-                "<a href=\"{}?backend={}\" style=\"{}\">{backend}</a>",
-                base_url,
-                backend.as_str(),
-                style,
-            )
-        };
-
-        links.push(link);
+        footer.append_child(&link)?;
     }
 
-    let footer_html = format!(
-        "<span style=\"color: #64748b;\">Backend:</span> {} | \
-         <span style=\"color: #64748b;\">FPS:</span> \
-         <span id=\"ratzilla-fps\" style=\"color: #4ade80; font-weight: bold;\">--</span>",
-        links.join(" | ")
-    );
-
-    footer.set_inner_html(&footer_html);
+    footer.append_child(&document.create_text_node("|"))?;
+    let fps_label = document.create_element("span")?;
+    fps_label.set_attribute("style", "color: #64748b;")?;
+    fps_label.set_text_content(Some("FPS:"));
+    footer.append_child(&fps_label)?;
+    let fps_value = document.create_element("span")?;
+    fps_value.set_id("ratzilla-fps");
+    fps_value.set_attribute("style", "color: #4ade80; font-weight: bold;")?;
+    fps_value.set_text_content(Some("--"));
+    footer.append_child(&fps_value)?;
 
     // Append to body
     let body = document.body().ok_or("No body")?;
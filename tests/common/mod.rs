@@ -0,0 +1,103 @@
+//! Key events and states shared by the integration tests.
+//!
+//! Every test binary compiles its own copy of this module and uses only part of it.
+#![allow(dead_code)]
+
+use ratzilla::event::{KeyCode, KeyEvent};
+use ratzilla::ratatui::layout::Position;
+use website::headless::{buffer_to_text, render_menu_to_buffer, render_to_buffer_with_ui};
+use website::models::topic::Topic;
+use website::state::State;
+
+pub const fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        ctrl: false,
+        alt: false,
+        shift: false,
+    }
+}
+
+pub const fn ctrl(c: char) -> KeyEvent {
+    KeyEvent {
+        ctrl: true,
+        ..key(KeyCode::Char(c))
+    }
+}
+
+pub const fn shift(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        shift: true,
+        ..key(code)
+    }
+}
+
+pub fn topic(id: &str) -> Topic {
+    Topic::from_id(id).expect("the topic exists in content/")
+}
+
+/// Text of the whole screen, rendered at 80x24
+pub fn screen(state: &mut State) -> String {
+    buffer_to_text(&render_to_buffer_with_ui(state, 80, 24))
+}
+
+/// Leave the intro for the topic list
+pub fn state_in_list() -> State {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state
+}
+
+/// Leave the intro and select `topic`
+pub fn state_with_topic(id: &str) -> State {
+    let mut state = state_in_list();
+    state.select_topic(Some(topic(id)));
+    state
+}
+
+/// Leave the intro and select the topic at `index` in the list
+pub fn state_with_selection(index: usize) -> State {
+    let mut state = state_in_list();
+    // Selected directly, going through the quote topic would draw a quote
+    state.select_topic(Topic::registry().nth(index));
+    state
+}
+
+/// Leave the intro and select the contact topic, rendered once
+pub fn state_with_contact() -> State {
+    let mut state = state_with_topic("contact");
+    let _ = render_menu_to_buffer(&mut state, 80, 24);
+    state
+}
+
+/// Leave the intro and open the quote book
+pub fn state_with_book() -> State {
+    let mut state = state_in_list();
+    state.open_quote(None);
+    state
+}
+
+/// Leave the intro and ask to quit
+pub fn quitting_state() -> State {
+    let mut state = state_in_list();
+    state.on_key_event(&key(KeyCode::Char('q')));
+    state
+}
+
+/// Render the menu and find the cell showing `text`
+pub fn menu_with(text: &str) -> (State, Position) {
+    let mut state = state_in_list();
+    let buffer = render_menu_to_buffer(&mut state, 80, 24);
+    let position = buffer_to_text(&buffer)
+        .lines()
+        .enumerate()
+        .find_map(|(row, line)| {
+            let column = line.find(text)?;
+            Some(Position::new(
+                u16::try_from(column).ok()?,
+                u16::try_from(row).ok()?,
+            ))
+        })
+        .expect("the text is rendered");
+    (state, position)
+}
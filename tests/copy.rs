@@ -0,0 +1,36 @@
+//! Tests of the copy action and of its toast.
+
+mod common;
+
+use common::{key, state_with_contact};
+use ratzilla::event::{KeyCode, KeyEvent};
+use website::headless::{buffer_to_text, render_menu_to_buffer};
+use website::state::State;
+
+#[test]
+fn nothing_selected() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Char('y')));
+    assert_eq!(state.toast(), Some("Select a topic to copy it"));
+}
+
+#[test]
+fn copy_reports_failures() {
+    // Native builds have no clipboard, every copy fails
+    let mut state = state_with_contact();
+    state.on_key_event(&key(KeyCode::Char('y')));
+    assert_eq!(state.toast(), Some("Could not copy Contact"));
+
+    state.on_key_event(&key(KeyCode::Tab));
+    state.on_key_event(&KeyEvent {
+        ctrl: true,
+        ..key(KeyCode::Char('c'))
+    });
+    assert_eq!(state.toast(), Some("Could not copy the Work link"));
+    let screen = buffer_to_text(&render_menu_to_buffer(&mut state, 80, 24));
+    assert!(
+        screen.contains("│ Could not copy the Work link │"),
+        "{screen}"
+    );
+}
@@ -0,0 +1,82 @@
+//! Tests of the fuzzy finder.
+
+mod common;
+
+use common::{key, topic};
+use ratzilla::event::KeyCode;
+use website::headless::{buffer_to_text, render_menu_to_buffer};
+use website::models::finder::{fuzzy_match, search};
+use website::models::quote::{Quote, QuoteMode, QuotePicker};
+use website::state::State;
+use website::storage::Visit;
+
+#[test]
+fn matches_in_order() {
+    let (_, positions) = fuzzy_match("gthb", "GitHub").unwrap();
+    assert_eq!(positions, [0, 2, 3, 5]);
+    assert!(fuzzy_match("bhtg", "GitHub").is_none());
+    assert!(fuzzy_match("", "anything").is_some());
+}
+
+#[test]
+fn ranks_close_matches_first() {
+    let (compact, _) = fuzzy_match("cv", "Cv").unwrap();
+    let (spread, _) = fuzzy_match("cv", "Curriculum vitae").unwrap();
+    assert!(compact > spread);
+    let results = search("cv", None);
+    assert_eq!(results[0].topic.id(), "cv");
+    assert_eq!(results[0].line, None);
+}
+
+#[test]
+fn searches_descriptions() {
+    let results = search("ko-fi", None);
+    let found = &results[0];
+    assert_eq!(found.topic.id(), "donate");
+    assert!(found.line.as_deref().unwrap().contains("Ko-fi"));
+    // An empty query lists every topic name
+    assert_eq!(search("", None).len(), 8);
+}
+
+#[test]
+fn enter_selects_the_result() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Char('/')));
+    for c in "kofi".chars() {
+        state.on_key_event(&key(KeyCode::Char(c)));
+    }
+    state.on_key_event(&key(KeyCode::Enter));
+    assert_eq!(
+        state.selected_topic().map(|topic| topic.id()),
+        Some("donate")
+    );
+    // The finder is closed, keys move the list again
+    state.on_key_event(&key(KeyCode::Down));
+    assert_eq!(
+        state.selected_topic().map(|topic| topic.id()),
+        Some("quote")
+    );
+}
+
+#[test]
+fn enter_keeps_the_quote_found() {
+    let quote = Quote::from_id("richard-stallman-1");
+    let mut state = State::without_effects();
+    state.set_quotes(QuotePicker::new(QuoteMode::Shuffle, Some(1)));
+    state.restore_visit(&Visit {
+        completed: Vec::new(),
+        selected: Some(topic("about")),
+        last_quote: quote,
+    });
+    state.on_key_event(&key(KeyCode::Char('/')));
+    for c in "friendship".chars() {
+        state.on_key_event(&key(KeyCode::Char(c)));
+    }
+    state.on_key_event(&key(KeyCode::Enter));
+    assert_eq!(state.selected_topic(), Some(topic("quote")));
+    // The quote searched is the one shown, not a new one, scrolled to the matched line
+    assert_eq!(state.visit().last_quote, quote);
+    let text = buffer_to_text(&render_menu_to_buffer(&mut state, 80, 12));
+    assert!(text.contains("friendship"), "{text}");
+}
@@ -0,0 +1,93 @@
+//! Tests of the key bindings and their presets.
+
+mod common;
+
+use common::{ctrl, key};
+use ratzilla::event::{KeyCode, KeyEvent};
+use website::keymap::{ARROWS, Action, EMACS, KEYMAPS, Keymap, VIM, WASD};
+
+#[test]
+fn presets() {
+    assert_eq!(WASD.action(&key(KeyCode::Char('s'))), Some(Action::Down));
+    assert_eq!(WASD.action(&key(KeyCode::Char('h'))), Some(Action::First));
+    assert_eq!(VIM.action(&key(KeyCode::Char('h'))), Some(Action::Back));
+    assert_eq!(VIM.action(&key(KeyCode::Char('j'))), Some(Action::Down));
+    assert_eq!(EMACS.action(&ctrl('n')), Some(Action::Down));
+    assert_eq!(EMACS.action(&key(KeyCode::Char('n'))), None);
+    assert_eq!(ARROWS.action(&key(KeyCode::Char('s'))), None);
+}
+
+#[test]
+fn common_bindings() {
+    for keymap in KEYMAPS {
+        assert_eq!(keymap.action(&key(KeyCode::Up)), Some(Action::Up));
+        assert_eq!(keymap.action(&key(KeyCode::Enter)), Some(Action::Read));
+        assert_eq!(
+            keymap.action(&KeyEvent {
+                ctrl: true,
+                ..key(KeyCode::Enter)
+            }),
+            Some(Action::OpenLink)
+        );
+        assert_eq!(keymap.action(&key(KeyCode::Char('?'))), Some(Action::Help));
+    }
+}
+
+#[test]
+fn shift_bindings() {
+    let shift_down = KeyEvent {
+        shift: true,
+        ..key(KeyCode::Down)
+    };
+    for keymap in KEYMAPS {
+        assert_eq!(keymap.action(&shift_down), Some(Action::ScrollDown));
+        assert_eq!(keymap.action(&key(KeyCode::PageUp)), Some(Action::PageUp));
+    }
+    // Shift is part of the character for letters
+    let shift_s = KeyEvent {
+        shift: true,
+        ..key(KeyCode::Char('S'))
+    };
+    assert_eq!(WASD.action(&shift_s), Some(Action::ScrollDown));
+    assert_eq!(VIM.action(&ctrl('d')), Some(Action::PageDown));
+}
+
+#[test]
+fn hint() {
+    let hints: [(&[Action], &str); 2] = [
+        (&[Action::Down, Action::Up], "move"),
+        (&[Action::OpenLink], "open link"),
+    ];
+    assert_eq!(
+        WASD.hint(&hints),
+        "↓↑ or sw to move, Ctrl+Enter to open link"
+    );
+    assert_eq!(
+        EMACS.hint(&hints),
+        "↓↑ or Ctrl+n/Ctrl+p to move, Ctrl+Enter to open link"
+    );
+}
+
+#[test]
+fn from_name() {
+    assert_eq!(Keymap::from_name("Vim"), Some(VIM));
+    assert_eq!(Keymap::from_name("dvorak"), None);
+    assert_eq!(Keymap::default().next(), ARROWS);
+}
+
+#[test]
+fn claimed_chords() {
+    assert!(VIM.claims(&ctrl('d')));
+    assert!(EMACS.claims(&ctrl('f')));
+    assert!(!WASD.claims(&ctrl('d')));
+    // Plain keys are typed text, only chords are kept from the browser
+    assert!(!VIM.claims(&key(KeyCode::Char('j'))));
+}
+
+#[test]
+fn every_preset_claims_the_finder_chord() {
+    // Ctrl+K would focus the browser search bar instead of opening or closing the finder
+    for keymap in KEYMAPS {
+        assert!(keymap.claims(&ctrl('k')), "{}", keymap.name);
+    }
+}
@@ -0,0 +1,70 @@
+//! Tests of the typed links of the topics and of the focused link.
+
+mod common;
+
+use common::{key, shift, state_with_topic, topic};
+use ratzilla::event::KeyCode;
+use website::headless::render_menu_to_buffer;
+use website::models::topic::LinkKind;
+use website::state::State;
+
+fn focused_label(state: &State) -> Option<&'static str> {
+    state.focused_link().map(|link| link.label)
+}
+
+#[test]
+fn typed_links() {
+    let labels = |id| -> Vec<_> {
+        topic(id)
+            .links()
+            .iter()
+            .map(|link| (link.kind, link.label))
+            .collect()
+    };
+    assert_eq!(
+        labels("contact"),
+        [
+            (LinkKind::Mail, "Work"),
+            (LinkKind::Mail, "Education"),
+            (LinkKind::Mail, "Personal"),
+        ]
+    );
+    assert_eq!(
+        labels("donate"),
+        [(LinkKind::Web, "PayPal"), (LinkKind::Web, "Ko-fi")]
+    );
+    assert_eq!(labels("cv"), [(LinkKind::Download, "CV")]);
+    assert!(topic("about").links().is_empty());
+}
+
+#[test]
+fn tab_cycles_links() {
+    let mut state = state_with_topic("contact");
+    assert_eq!(focused_label(&state), None);
+    for expected in ["Work", "Education", "Personal", "Work"] {
+        state.on_key_event(&key(KeyCode::Tab));
+        assert_eq!(focused_label(&state), Some(expected));
+    }
+    state.on_key_event(&shift(KeyCode::Tab));
+    assert_eq!(focused_label(&state), Some("Personal"));
+
+    // Shift+Tab starts from the last link, topics without links ignore Tab
+    let mut state = state_with_topic("donate");
+    state.on_key_event(&shift(KeyCode::Tab));
+    assert_eq!(focused_label(&state), Some("Ko-fi"));
+    let mut state = state_with_topic("about");
+    state.on_key_event(&key(KeyCode::Tab));
+    assert_eq!(focused_label(&state), None);
+}
+
+#[test]
+fn focus_is_kept_per_topic() {
+    let mut state = state_with_topic("contact");
+    state.on_key_event(&key(KeyCode::Tab));
+    state.on_key_event(&key(KeyCode::Tab));
+    let _ = render_menu_to_buffer(&mut state, 80, 24);
+    state.on_key_event(&key(KeyCode::Down));
+    assert_eq!(focused_label(&state), None);
+    state.on_key_event(&key(KeyCode::Up));
+    assert_eq!(focused_label(&state), Some("Education"));
+}
@@ -0,0 +1,35 @@
+//! Tests of the motion policies.
+
+mod common;
+
+use common::key;
+use ratzilla::event::KeyCode;
+use website::motion::MotionPolicy;
+use website::state::State;
+
+#[test]
+fn from_name() {
+    assert_eq!(
+        MotionPolicy::from_name("Reduced"),
+        Some(MotionPolicy::Reduced)
+    );
+    assert_eq!(MotionPolicy::from_name("none"), Some(MotionPolicy::None));
+    assert_eq!(MotionPolicy::from_name("slow"), None);
+}
+
+#[test]
+fn toggle_in_the_list() {
+    let mut state = State::default();
+    assert_eq!(state.motion(), MotionPolicy::Full);
+    // The first key only leaves the intro
+    state.on_key_event(&key(KeyCode::Char('m')));
+    assert_eq!(state.motion(), MotionPolicy::Full);
+    for expected in [
+        MotionPolicy::Reduced,
+        MotionPolicy::None,
+        MotionPolicy::Full,
+    ] {
+        state.on_key_event(&key(KeyCode::Char('m')));
+        assert_eq!(state.motion(), expected);
+    }
+}
@@ -0,0 +1,46 @@
+//! Mouse events mapped onto the cells of the rendered menu.
+
+mod common;
+
+use common::{menu_with, topic};
+use ratzilla::ratatui::layout::Position;
+use website::pointer::{MouseEvent, MouseEventKind};
+
+const fn mouse(kind: MouseEventKind, position: Position) -> MouseEvent {
+    MouseEvent { kind, position }
+}
+
+#[test]
+fn click_selects_topic() {
+    let (mut state, position) = menu_with("Donate");
+    state.on_mouse_event(&mouse(MouseEventKind::Click, position));
+    assert_eq!(state.selected_topic(), Some(topic("donate")));
+    assert!(state.visit().completed.is_empty());
+}
+
+#[test]
+fn double_click_toggles_status() {
+    let (mut state, position) = menu_with("Social");
+    state.on_mouse_event(&mouse(MouseEventKind::Click, position));
+    state.on_mouse_event(&mouse(MouseEventKind::DoubleClick, position));
+    assert_eq!(state.visit().completed, vec![topic("social")]);
+}
+
+#[test]
+fn wheel_moves_selection() {
+    let (mut state, position) = menu_with("Cv");
+    state.on_mouse_event(&mouse(MouseEventKind::Click, position));
+    state.on_mouse_event(&mouse(MouseEventKind::ScrollDown, position));
+    assert_eq!(state.selected_topic(), Some(topic("donate")));
+    state.on_mouse_event(&mouse(MouseEventKind::ScrollUp, position));
+    state.on_mouse_event(&mouse(MouseEventKind::ScrollUp, position));
+    assert_eq!(state.selected_topic(), Some(topic("contact")));
+}
+
+#[test]
+fn click_outside_list_keeps_selection() {
+    let (mut state, position) = menu_with("Cv");
+    state.on_mouse_event(&mouse(MouseEventKind::Click, position));
+    state.on_mouse_event(&mouse(MouseEventKind::Click, Position::new(0, 0)));
+    assert_eq!(state.selected_topic(), Some(topic("cv")));
+}
@@ -0,0 +1,36 @@
+//! Tests of the exit flow started by `q`.
+
+mod common;
+
+use common::{key, quitting_state, screen};
+use ratzilla::event::KeyCode;
+
+#[test]
+fn goodbye_then_intro() {
+    let mut state = quitting_state();
+    assert!(screen(&mut state).contains("Goodbye, thanks for visiting!"));
+    assert!(!state.take_exited());
+    // The goodbye lasts a few seconds of 30ms frames
+    let frames = (0..500)
+        .position(|_| {
+            screen(&mut state);
+            state.take_exited()
+        })
+        .expect("the goodbye never ends");
+    assert!(frames > 10, "{frames}");
+    assert!(screen(&mut state).contains("PRESS ANY KEY TO START"));
+    assert!(!state.take_exited());
+}
+
+#[test]
+fn any_key_undoes_the_exit() {
+    let mut state = quitting_state();
+    screen(&mut state);
+    state.on_key_event(&key(KeyCode::Char('x')));
+    let text = screen(&mut state);
+    assert!(text.contains("Daniele's public data"), "{text}");
+    for _ in 0..500 {
+        screen(&mut state);
+    }
+    assert!(!state.take_exited());
+}
@@ -0,0 +1,113 @@
+//! Tests of how quotes are drawn.
+
+mod common;
+
+use common::key;
+use ratzilla::event::KeyCode;
+use website::headless::render_menu_to_buffer;
+use website::models::quote::{Quote, QuoteMode, QuotePicker, daily_index};
+use website::models::topic::Topic;
+use website::state::State;
+use website::storage::Visit;
+
+fn draws(picker: &mut QuotePicker, count: usize, draws: usize) -> Vec<usize> {
+    (0..draws).filter_map(|_| picker.next(count)).collect()
+}
+
+#[test]
+fn seeds_reproduce_draws() {
+    for mode in [QuoteMode::Random, QuoteMode::Shuffle] {
+        let first = draws(&mut QuotePicker::new(mode, Some(42)), 13, 30);
+        let second = draws(&mut QuotePicker::new(mode, Some(42)), 13, 30);
+        assert_eq!(first, second);
+    }
+    assert_eq!(QuotePicker::default().next(0), None);
+}
+
+#[test]
+fn shuffle_never_repeats() {
+    let mut picker = QuotePicker::new(QuoteMode::Shuffle, Some(3));
+    let drawn = draws(&mut picker, 5, 50);
+    // Every bag holds each quote once, and a new bag does not start with the last quote
+    for bag in drawn.chunks(5) {
+        let mut sorted = bag.to_vec();
+        sorted.sort_unstable();
+        assert_eq!(sorted, [0, 1, 2, 3, 4]);
+    }
+    assert!(drawn.windows(2).all(|pair| pair[0] != pair[1]), "{drawn:?}");
+}
+
+#[test]
+fn daily_quote() {
+    assert_eq!(daily_index(20_000, 13), daily_index(20_000, 13));
+    let week: Vec<usize> = (20_000..20_007).map(|day| daily_index(day, 13)).collect();
+    assert!(week.iter().any(|index| *index != week[0]), "{week:?}");
+    assert!(week.iter().all(|index| *index < 13));
+    // The seed does not change the quote of the day
+    let mut seeded = QuotePicker::new(QuoteMode::Daily, Some(1));
+    let mut other = QuotePicker::new(QuoteMode::Daily, Some(2));
+    assert_eq!(seeded.next(13), other.next(13));
+    assert_eq!(QuoteMode::from_name("Daily"), Some(QuoteMode::Daily));
+}
+
+#[test]
+fn quotes_change_on_selection_only() {
+    let mut state = State::without_effects();
+    state.set_quotes(QuotePicker::new(QuoteMode::Shuffle, Some(5)));
+    state.on_key_event(&key(KeyCode::Down));
+    state.select_topic(Topic::from_id("quote"));
+    let _ = render_menu_to_buffer(&mut state, 80, 24);
+    let shown = state.screen_reader_view().detail;
+    for _ in 0..5 {
+        let _ = render_menu_to_buffer(&mut state, 80, 24);
+        assert_eq!(state.screen_reader_view().detail, shown);
+    }
+    // Selecting the topic again draws another quote
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Up));
+    let _ = render_menu_to_buffer(&mut state, 80, 24);
+    assert_ne!(state.screen_reader_view().detail, shown);
+}
+
+#[test]
+fn sessions_keep_their_own_quote() {
+    let mut sessions: Vec<State> = [1, 2]
+        .into_iter()
+        .map(|seed| {
+            let mut state = State::without_effects();
+            state.set_quotes(QuotePicker::new(QuoteMode::Shuffle, Some(seed)));
+            state.on_key_event(&key(KeyCode::Down));
+            state.select_topic(Topic::from_id("quote"));
+            state
+        })
+        .collect();
+    let shown: Vec<_> = sessions
+        .iter()
+        .map(|state| state.visit().last_quote)
+        .collect();
+    assert!(shown.iter().all(Option::is_some));
+    // Drawing quotes in one session leaves the other one as it was
+    for _ in 0..3 {
+        sessions[0].on_key_event(&key(KeyCode::Down));
+        sessions[0].on_key_event(&key(KeyCode::Up));
+    }
+    assert_ne!(sessions[0].visit().last_quote, shown[0]);
+    assert_eq!(sessions[1].visit().last_quote, shown[1]);
+}
+
+#[test]
+fn selecting_without_moving_keeps_the_quote() {
+    let quote = Quote::from_id("f-scott-fitzgerald-1");
+    let mut state = State::without_effects();
+    state.set_quotes(QuotePicker::new(QuoteMode::Shuffle, Some(3)));
+    state.restore_visit(&Visit {
+        completed: Vec::new(),
+        selected: Topic::from_id("quote"),
+        last_quote: quote,
+    });
+    assert_eq!(state.visit().last_quote, quote);
+    // Links select the topic the same way
+    state.select_topic(Topic::from_id("about"));
+    state.select_topic(Topic::from_id("quote"));
+    assert_eq!(state.visit().last_quote, quote);
+}
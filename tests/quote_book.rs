@@ -0,0 +1,114 @@
+//! Tests of the quote book, browsing every quote.
+
+mod common;
+
+use common::{key, state_with_book};
+use ratzilla::event::{KeyCode, KeyEvent};
+use website::headless::{buffer_to_text, render_quotes_to_buffer};
+use website::models::quote::Quote;
+use website::state::State;
+
+fn type_text(state: &mut State, text: &str) {
+    for c in text.chars() {
+        state.on_key_event(&key(KeyCode::Char(c)));
+    }
+}
+
+fn shown_id(state: &State) -> Option<&'static str> {
+    state.book_quote().map(|quote| quote.id)
+}
+
+#[test]
+fn quotes_data() {
+    let quote = Quote::from_id("edsger-w-dijkstra-2").unwrap();
+    assert_eq!(quote.source, Some("The threats to computing science"));
+    assert_eq!(quote.year, Some(1984));
+    assert!(quote.tags.contains(&"artificial intelligence"));
+    assert!(
+        quote
+            .to_string()
+            .ends_with("- Edsger W. Dijkstra, The threats to computing science (1984)")
+    );
+    assert!(Quote::all().iter().all(|quote| !quote.tags.is_empty()));
+    assert_eq!(Quote::from_id("nobody-1"), None);
+}
+
+#[test]
+fn browse_around_the_ends() {
+    let mut state = state_with_book();
+    assert_eq!(shown_id(&state), Some(Quote::all()[0].id));
+    state.on_key_event(&key(KeyCode::Left));
+    assert_eq!(shown_id(&state), Quote::all().last().map(|quote| quote.id));
+    state.on_key_event(&key(KeyCode::Right));
+    state.on_key_event(&key(KeyCode::Char('n')));
+    assert_eq!(shown_id(&state), Some(Quote::all()[1].id));
+}
+
+#[test]
+fn filter_by_author_or_tag() {
+    let mut state = state_with_book();
+    state.on_key_event(&key(KeyCode::Char('/')));
+    type_text(&mut state, "dijkstra");
+    state.on_key_event(&key(KeyCode::Enter));
+    assert_eq!(shown_id(&state), Some("edsger-w-dijkstra-1"));
+    // Browsing stays among the matches
+    let mut seen = Vec::new();
+    for _ in 0..3 {
+        state.on_key_event(&key(KeyCode::Right));
+        seen.extend(shown_id(&state));
+    }
+    assert_eq!(
+        seen,
+        [
+            "edsger-w-dijkstra-2",
+            "edsger-w-dijkstra-3",
+            "edsger-w-dijkstra-1"
+        ]
+    );
+
+    // Tags match too, and a filter matching nothing shows no quote
+    state.on_key_event(&key(KeyCode::Char('/')));
+    state.on_key_event(&KeyEvent {
+        ctrl: true,
+        ..key(KeyCode::Char('u'))
+    });
+    type_text(&mut state, "Perseverance");
+    assert_eq!(shown_id(&state), Some("napoleon-bonaparte-1"));
+    type_text(&mut state, "xyz");
+    assert_eq!(shown_id(&state), None);
+    let screen = buffer_to_text(&render_quotes_to_buffer(&mut state, 80, 24));
+    assert!(
+        screen.contains("No quote by this author or with this tag"),
+        "{screen}"
+    );
+}
+
+#[test]
+fn share_reports_failures() {
+    // Native builds have no clipboard, every copy fails
+    let mut state = state_with_book();
+    state.on_key_event(&key(KeyCode::Char('s')));
+    assert_eq!(state.toast(), Some("Could not copy the quote"));
+}
+
+#[test]
+fn escape_stops_filtering_then_leaves() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Char('"')));
+    assert!(state.book_quote().is_some());
+    state.on_key_event(&key(KeyCode::Char('/')));
+    state.on_key_event(&key(KeyCode::Esc));
+    assert!(state.book_quote().is_some());
+    state.on_key_event(&key(KeyCode::Esc));
+    assert_eq!(state.book_quote(), None);
+}
+
+#[test]
+fn quote_book_snapshot() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.open_quote(Quote::from_id("edsger-w-dijkstra-2"));
+    let buffer = render_quotes_to_buffer(&mut state, 80, 24);
+    insta::assert_snapshot!("quote_book_80x24", buffer_to_text(&buffer));
+}
@@ -0,0 +1,81 @@
+//! Tests of the scene stack: which scene is on top and what is left below it.
+
+mod common;
+
+use common::{key, screen};
+use ratzilla::event::KeyCode;
+use website::headless::{
+    buffer_to_text, render_intro_to_buffer, render_menu_to_buffer, render_shell_to_buffer,
+};
+use website::models::topic::Topic;
+use website::state::State;
+
+fn scene(state: &State) -> String {
+    state.screen_reader_view().scene.into_owned()
+}
+
+#[test]
+fn scenes_go_back_to_the_one_below() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.select_topic(Topic::from_id("cv"));
+    state.on_key_event(&key(KeyCode::Char(':')));
+    assert!(scene(&state).starts_with("Shell."));
+    state.on_key_event(&key(KeyCode::Esc));
+    assert!(scene(&state).starts_with("Topics."));
+    // Esc in the list goes back to the intro, any key comes back to the same topic
+    state.on_key_event(&key(KeyCode::Esc));
+    assert!(screen(&mut state).contains("PRESS ANY KEY TO START"));
+    state.on_key_event(&key(KeyCode::Char('x')));
+    assert_eq!(state.selected_topic(), Topic::from_id("cv"));
+    assert!(!screen(&mut state).contains("PRESS ANY KEY TO START"));
+}
+
+#[test]
+fn popups_keep_the_list_below() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Char('/')));
+    // Screen readers keep reading the list under the finder
+    assert!(scene(&state).starts_with("Topics."));
+    let text = screen(&mut state);
+    assert!(text.contains("Daniele's public data"), "{text}");
+    state.on_key_event(&key(KeyCode::Esc));
+    state.on_key_event(&key(KeyCode::Char('?')));
+    assert!(screen(&mut state).contains("Keys, wasd preset"));
+    state.on_key_event(&key(KeyCode::Char('x')));
+    assert!(!screen(&mut state).contains("Keys, wasd preset"));
+    assert!(scene(&state).starts_with("Topics."));
+}
+
+#[test]
+fn linked_topics_close_every_scene_over_the_list() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Char('"')));
+    assert!(state.book_quote().is_some());
+    state.select_topic(Topic::from_id("social"));
+    assert_eq!(state.book_quote(), None);
+    assert!(scene(&state).starts_with("Topics."));
+    // Closing the list goes back to the intro, not to the quote book
+    state.on_key_event(&key(KeyCode::Esc));
+    assert!(scene(&state).starts_with("Daniele Giachetto's website."));
+}
+
+#[test]
+fn headless_renders_leave_the_stack() {
+    let mut state = State::without_effects();
+    let depth = state.depth();
+    let text = buffer_to_text(&render_intro_to_buffer(&mut state, 80, 24));
+    assert!(text.contains("PRESS ANY KEY TO START"), "{text}");
+    let text = buffer_to_text(&render_menu_to_buffer(&mut state, 80, 24));
+    assert!(text.contains("Daniele's public data"), "{text}");
+    let _ = render_intro_to_buffer(&mut state, 80, 24);
+    let text = buffer_to_text(&render_shell_to_buffer(&mut state, 80, 24));
+    assert!(text.contains("Type help"), "{text}");
+    assert_eq!(state.depth(), depth);
+    // Keys still go to the intro, the scene on top
+    assert!(scene(&state).starts_with("Daniele Giachetto's website."));
+    state.on_key_event(&key(KeyCode::Char('x')));
+    assert!(scene(&state).starts_with("Topics."));
+}
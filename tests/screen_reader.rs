@@ -0,0 +1,93 @@
+//! Tests of what screen readers are told.
+
+mod common;
+
+use common::key;
+use ratzilla::event::KeyCode;
+use website::headless::render_menu_to_buffer;
+use website::keymap::VIM;
+use website::screen_reader::announcement;
+use website::state::State;
+
+/// Apply `code`, render and return the announcement of the change
+fn announce_after(state: &mut State, code: KeyCode) -> Option<String> {
+    let previous = state.screen_reader_view();
+    state.on_key_event(&key(code));
+    let _ = render_menu_to_buffer(state, 80, 24);
+    announcement(&previous, &state.screen_reader_view())
+}
+
+#[test]
+fn scene_change() {
+    let mut state = State::without_effects();
+    let announced = announce_after(&mut state, KeyCode::Down).unwrap();
+    assert!(announced.starts_with("Topics."), "{announced}");
+}
+
+#[test]
+fn selection_and_status() {
+    let mut state = State::without_effects();
+    announce_after(&mut state, KeyCode::Down);
+    let announced = announce_after(&mut state, KeyCode::Down).unwrap();
+    assert!(
+        announced.starts_with("About, to read, 1 of 8."),
+        "{announced}"
+    );
+    // The description is read without its ASCII art
+    assert!(
+        announced.contains("I'm Daniele Giachetto aka RakuJa"),
+        "{announced}"
+    );
+    assert!(!announced.contains('█'), "{announced}");
+    assert_eq!(
+        announce_after(&mut state, KeyCode::Right).as_deref(),
+        Some("About marked as read")
+    );
+    assert_eq!(announce_after(&mut state, KeyCode::Char('x')), None);
+}
+
+#[test]
+fn list_semantics() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Down));
+    let _ = render_menu_to_buffer(&mut state, 80, 24);
+    let view = state.screen_reader_view();
+    assert_eq!(view.topics.len(), 8);
+    assert_eq!(view.selected, Some(1));
+    assert!(
+        view.detail
+            .iter()
+            .any(|line| line.contains("mailto:work@danielegiachetto.com"))
+    );
+}
+
+#[test]
+fn focused_link() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Down));
+    announce_after(&mut state, KeyCode::Down);
+    assert_eq!(
+        announce_after(&mut state, KeyCode::Tab).as_deref(),
+        Some("Work, mail link")
+    );
+}
+
+#[test]
+fn scene_follows_the_keymap() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    let scene = state.screen_reader_view().scene;
+    assert!(scene.contains("↑↓ or ws to move"), "{scene}");
+    assert!(scene.contains("→ or d to mark"), "{scene}");
+    assert!(scene.contains(": to open the shell"), "{scene}");
+    state.set_keymap(VIM);
+    let scene = state.screen_reader_view().scene;
+    assert!(scene.contains("↑↓ or kj to move"), "{scene}");
+    assert!(scene.contains("→ or l to mark"), "{scene}");
+    // Switching presets is announced with the new keys
+    let announced = announce_after(&mut state, KeyCode::Char('p')).unwrap();
+    assert!(announced.contains("Ctrl+p"), "{announced}");
+}
@@ -0,0 +1,79 @@
+//! Command parsing, history and completion of the interactive shell.
+
+mod common;
+
+use common::{key, topic};
+use ratzilla::event::KeyCode;
+use website::models::shell::{Command, ParseError, Shell};
+
+fn type_line(shell: &mut Shell, line: &str) {
+    for c in line.chars() {
+        shell.on_key_event(&key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn parse_commands() {
+    assert_eq!("help".parse(), Ok(Command::Help));
+    assert_eq!("  LS ".parse(), Ok(Command::Ls));
+    assert_eq!("about".parse(), Ok(Command::Show(topic("about"))));
+    assert_eq!("open social".parse(), Ok(Command::Open(topic("social"))));
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!("".parse::<Command>(), Err(ParseError::Empty));
+    assert_eq!(
+        "ls -la".parse::<Command>(),
+        Err(ParseError::TooManyArguments("ls".to_string()))
+    );
+    assert_eq!(
+        "rm -rf".parse::<Command>(),
+        Err(ParseError::UnknownCommand("rm".to_string()))
+    );
+    assert_eq!(
+        "open".parse::<Command>(),
+        Err(ParseError::MissingArgument("open"))
+    );
+    assert_eq!(
+        "open moon".parse::<Command>(),
+        Err(ParseError::UnknownTopic("moon".to_string()))
+    );
+}
+
+#[test]
+fn history() {
+    let mut shell = Shell::default();
+    for line in ["about", "ls", "ls"] {
+        type_line(&mut shell, line);
+        shell.on_key_event(&key(KeyCode::Enter));
+    }
+    type_line(&mut shell, "he");
+    shell.on_key_event(&key(KeyCode::Up));
+    assert_eq!(shell.input(), "ls");
+    shell.on_key_event(&key(KeyCode::Up));
+    assert_eq!(shell.input(), "about");
+    shell.on_key_event(&key(KeyCode::Up));
+    assert_eq!(shell.input(), "about");
+    shell.on_key_event(&key(KeyCode::Down));
+    shell.on_key_event(&key(KeyCode::Down));
+    assert_eq!(shell.input(), "he");
+}
+
+#[test]
+fn completion() {
+    let mut shell = Shell::default();
+    type_line(&mut shell, "cr");
+    shell.on_key_event(&key(KeyCode::Tab));
+    assert_eq!(shell.input(), "credits ");
+
+    let mut shell = Shell::default();
+    type_line(&mut shell, "open so");
+    shell.on_key_event(&key(KeyCode::Tab));
+    assert_eq!(shell.input(), "open social ");
+
+    let mut shell = Shell::default();
+    type_line(&mut shell, "c");
+    shell.on_key_event(&key(KeyCode::Tab));
+    assert_eq!(shell.input(), "c");
+}
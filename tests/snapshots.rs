@@ -0,0 +1,228 @@
+//! Snapshot tests of every scene, rendered headlessly at several terminal sizes.
+//!
+//! Run `cargo insta review` after an intended layout change to update the snapshots.
+
+mod common;
+
+use common::{key, state_with_selection};
+use ratzilla::event::KeyCode;
+use website::headless::{
+    buffer_to_styled_text, buffer_to_text, render_intro_to_buffer, render_menu_to_buffer,
+    render_shell_to_buffer, render_to_buffer_with_ui,
+};
+use website::models::quote::{QuoteMode, QuotePicker};
+use website::models::topic::Topic;
+use website::state::State;
+
+/// Terminal sizes every scene is rendered at: classic, laptop and wide monitor
+const SIZES: [(u16, u16); 3] = [(80, 24), (120, 40), (200, 60)];
+
+/// Topic names in list order, used to name the snapshots
+const TOPICS: [&str; 8] = [
+    "about", "contact", "cv", "donate", "quote", "social", "summary", "credits",
+];
+
+/// Index of the quote topic, its description is random and gets its own test
+const QUOTE_INDEX: usize = 4;
+
+fn assert_menu_snapshots(state: &mut State, name: &str) {
+    for (width, height) in SIZES {
+        let buffer = render_menu_to_buffer(state, width, height);
+        insta::assert_snapshot!(
+            format!("menu_{name}_{width}x{height}"),
+            buffer_to_text(&buffer)
+        );
+    }
+}
+
+#[test]
+fn intro() {
+    for (width, height) in SIZES {
+        let buffer = render_intro_to_buffer(&mut State::without_effects(), width, height);
+        insta::assert_snapshot!(format!("intro_{width}x{height}"), buffer_to_text(&buffer));
+    }
+}
+
+#[test]
+fn menu_without_selection() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    assert_menu_snapshots(&mut state, "none");
+}
+
+#[test]
+fn menu_todo_topics() {
+    for (index, name) in TOPICS.iter().enumerate() {
+        if index == QUOTE_INDEX {
+            continue;
+        }
+        assert_menu_snapshots(&mut state_with_selection(index), &format!("{name}_todo"));
+    }
+}
+
+#[test]
+fn menu_completed_topics() {
+    for (index, name) in TOPICS.iter().enumerate() {
+        if index == QUOTE_INDEX {
+            continue;
+        }
+        let mut state = state_with_selection(index);
+        state.on_key_event(&key(KeyCode::Right));
+        assert_menu_snapshots(&mut state, &format!("{name}_completed"));
+    }
+}
+
+#[test]
+fn menu_quote() {
+    let mut state = State::without_effects();
+    state.set_quotes(QuotePicker::new(QuoteMode::Random, Some(7)));
+    state.on_key_event(&key(KeyCode::Down));
+    state.select_topic(Topic::from_id("quote"));
+    assert_menu_snapshots(&mut state, "quote_todo");
+    // A completed quote keeps showing the last quote drawn
+    state.on_key_event(&key(KeyCode::Right));
+    assert_menu_snapshots(&mut state, "quote_completed");
+}
+
+#[test]
+fn menu_styles() {
+    let mut state = state_with_selection(0);
+    state.on_key_event(&key(KeyCode::Right));
+    let buffer = render_menu_to_buffer(&mut state, 80, 24);
+    insta::assert_snapshot!(
+        "menu_about_completed_styled",
+        buffer_to_styled_text(&buffer)
+    );
+}
+
+#[test]
+fn shell() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Char(':')));
+    for command in ["help", "ls", "cv", "cat cv"] {
+        for c in command.chars() {
+            state.on_key_event(&key(KeyCode::Char(c)));
+        }
+        state.on_key_event(&key(KeyCode::Enter));
+    }
+    state.on_key_event(&key(KeyCode::Char('s')));
+    state.on_key_event(&key(KeyCode::Tab));
+    for (width, height) in SIZES {
+        let buffer = render_shell_to_buffer(&mut state, width, height);
+        insta::assert_snapshot!(format!("shell_{width}x{height}"), buffer_to_text(&buffer));
+    }
+}
+
+#[test]
+fn menu_narrow() {
+    let mut state = state_with_selection(0);
+    let buffer = render_menu_to_buffer(&mut state, 40, 20);
+    insta::assert_snapshot!("menu_narrow_list", buffer_to_text(&buffer));
+    // Narrow screens open the selected topic on its own pane
+    state.on_key_event(&key(KeyCode::Enter));
+    let buffer = render_menu_to_buffer(&mut state, 40, 20);
+    insta::assert_snapshot!("menu_narrow_about", buffer_to_text(&buffer));
+    state.on_key_event(&key(KeyCode::Left));
+    let buffer = render_menu_to_buffer(&mut state, 40, 20);
+    insta::assert_snapshot!("menu_narrow_back", buffer_to_text(&buffer));
+}
+
+#[test]
+fn menu_narrow_past_the_end() {
+    // The open detail pane hides the list, nothing clamps the selection moved past the end
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::End));
+    let _ = render_to_buffer_with_ui(&mut state, 40, 20);
+    state.on_key_event(&key(KeyCode::Enter));
+    let _ = render_to_buffer_with_ui(&mut state, 40, 20);
+    state.on_key_event(&key(KeyCode::Down));
+    let text = buffer_to_text(&render_to_buffer_with_ui(&mut state, 40, 20));
+    assert!(text.contains("credits:"), "{text}");
+}
+
+#[test]
+fn keys_before_the_first_render() {
+    // Frontends may batch several keys before a frame, End then goes past the last topic
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::End));
+    state.on_key_event(&key(KeyCode::Right));
+    assert_eq!(state.selected_topic(), Topic::from_id("credits"));
+    assert_eq!(
+        state.visit().completed,
+        [Topic::from_id("credits").unwrap()]
+    );
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Up));
+    assert_eq!(state.selected_topic(), Topic::from_id("summary"));
+}
+
+#[test]
+fn menu_light_theme() {
+    let mut state = state_with_selection(0);
+    state.set_theme(website::theme::LIGHT);
+    let buffer = render_menu_to_buffer(&mut state, 80, 24);
+    insta::assert_snapshot!("menu_about_light_styled", buffer_to_styled_text(&buffer));
+}
+
+#[test]
+fn help_popup() {
+    let mut state = state_with_selection(0);
+    state.on_key_event(&key(KeyCode::Char('?')));
+    let buffer = render_menu_to_buffer(&mut state, 80, 24);
+    insta::assert_snapshot!("help_wasd", buffer_to_text(&buffer));
+    // Switching presets keeps the popup open, any other key closes it
+    state.on_key_event(&key(KeyCode::Char('p')));
+    state.on_key_event(&key(KeyCode::Char('p')));
+    let buffer = render_menu_to_buffer(&mut state, 80, 24);
+    insta::assert_snapshot!("help_vim", buffer_to_text(&buffer));
+    state.on_key_event(&key(KeyCode::Char('x')));
+    let buffer = render_menu_to_buffer(&mut state, 80, 24);
+    insta::assert_snapshot!("menu_vim_80x24", buffer_to_text(&buffer));
+}
+
+#[test]
+fn finder() {
+    let mut state = State::without_effects();
+    state.on_key_event(&key(KeyCode::Down));
+    state.on_key_event(&key(KeyCode::Char('/')));
+    for c in "git".chars() {
+        state.on_key_event(&key(KeyCode::Char(c)));
+    }
+    let buffer = render_menu_to_buffer(&mut state, 80, 24);
+    insta::assert_snapshot!("finder_80x24", buffer_to_text(&buffer));
+    // The detail pane scrolls to the matched line
+    state.on_key_event(&key(KeyCode::Enter));
+    let buffer = render_menu_to_buffer(&mut state, 80, 24);
+    insta::assert_snapshot!("finder_selected_80x24", buffer_to_text(&buffer));
+}
+
+#[test]
+fn detail_scroll() {
+    let summary = TOPICS.iter().position(|t| *t == "summary").unwrap();
+    let mut state = state_with_selection(summary);
+    let top = buffer_to_text(&render_menu_to_buffer(&mut state, 80, 24));
+    state.on_key_event(&key(KeyCode::PageDown));
+    let buffer = render_menu_to_buffer(&mut state, 80, 24);
+    insta::assert_snapshot!("menu_summary_page_down_80x24", buffer_to_text(&buffer));
+    // Selecting another topic and coming back starts from the top again
+    state.on_key_event(&key(KeyCode::Down));
+    let _ = render_menu_to_buffer(&mut state, 80, 24);
+    state.on_key_event(&key(KeyCode::Up));
+    assert_eq!(
+        buffer_to_text(&render_menu_to_buffer(&mut state, 80, 24)),
+        top
+    );
+}
+
+#[test]
+fn focused_link() {
+    let contact = TOPICS.iter().position(|t| *t == "contact").unwrap();
+    let mut state = state_with_selection(contact);
+    state.on_key_event(&key(KeyCode::Tab));
+    state.on_key_event(&key(KeyCode::Tab));
+    let buffer = render_menu_to_buffer(&mut state, 120, 40);
+    insta::assert_snapshot!("menu_contact_link_styled", buffer_to_styled_text(&buffer));
+}
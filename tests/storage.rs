@@ -0,0 +1,52 @@
+//! Tests of the visits kept for returning visitors, restored where they were left.
+
+mod common;
+
+use common::topic;
+use website::headless::render_menu_to_buffer;
+use website::models::quote::{Quote, QuoteMode, QuotePicker};
+use website::state::State;
+use website::storage::Visit;
+
+/// Restore `visit` in a new session, as a reload does
+fn restored(visit: &Visit) -> State {
+    let mut state = State::without_effects();
+    state.set_quotes(QuotePicker::new(QuoteMode::Shuffle, Some(11)));
+    state.restore_visit(visit);
+    state
+}
+
+#[test]
+fn visits_round_trip() {
+    let visit = Visit {
+        completed: vec![topic("about"), topic("contact")],
+        selected: Some(topic("cv")),
+        last_quote: Quote::from_id("edsger-w-dijkstra-2"),
+    };
+    let state = restored(&visit);
+    assert_eq!(state.visit(), visit);
+    assert!(state.screen_reader_view().scene.starts_with("Topics."));
+}
+
+#[test]
+fn selected_quote_topic_keeps_its_quote() {
+    for id in ["f-scott-fitzgerald-1", "richard-stallman-1"] {
+        let visit = Visit {
+            completed: Vec::new(),
+            selected: Some(topic("quote")),
+            last_quote: Quote::from_id(id),
+        };
+        let mut state = restored(&visit);
+        assert_eq!(state.visit(), visit);
+        let _ = render_menu_to_buffer(&mut state, 80, 24);
+        let detail = state.screen_reader_view().detail.join("\n");
+        assert!(detail.contains(visit.last_quote.unwrap().text), "{detail}");
+    }
+}
+
+#[test]
+fn first_visit() {
+    let state = restored(&Visit::default());
+    assert_eq!(state.selected_topic(), None);
+    assert!(state.visit().completed.is_empty());
+}
@@ -0,0 +1,18 @@
+//! Tests of the colour palettes.
+
+use website::theme::{THEMES, Theme};
+
+#[test]
+fn from_name() {
+    assert_eq!(Theme::from_name("Gruvbox").map(|t| t.name), Some("gruvbox"));
+    assert_eq!(Theme::from_name("unknown"), None);
+}
+
+#[test]
+fn next_cycles_through_every_theme() {
+    let mut theme = Theme::default();
+    for expected in THEMES.iter().skip(1).chain(&THEMES[..1]) {
+        theme = theme.next();
+        assert_eq!(theme, *expected);
+    }
+}
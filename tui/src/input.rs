@@ -0,0 +1,264 @@
+use ratatui::crossterm::event::{
+    KeyCode as CrosstermKeyCode, KeyEvent as CrosstermKeyEvent, KeyModifiers,
+};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Translate a crossterm key event into the key event shared with the browser frontend.
+///
+/// Returns `None` for keys that have no browser equivalent.
+pub const fn to_key_event(event: &CrosstermKeyEvent) -> Option<KeyEvent> {
+    let code = match event.code {
+        CrosstermKeyCode::Char(c) => KeyCode::Char(c),
+        CrosstermKeyCode::F(n) => KeyCode::F(n),
+        CrosstermKeyCode::Backspace => KeyCode::Backspace,
+        CrosstermKeyCode::Enter => KeyCode::Enter,
+        CrosstermKeyCode::Left => KeyCode::Left,
+        CrosstermKeyCode::Right => KeyCode::Right,
+        CrosstermKeyCode::Up => KeyCode::Up,
+        CrosstermKeyCode::Down => KeyCode::Down,
+        CrosstermKeyCode::Tab => KeyCode::Tab,
+        CrosstermKeyCode::BackTab => {
+            return Some(KeyEvent {
+                code: KeyCode::Tab,
+                ctrl: false,
+                alt: false,
+                shift: true,
+            });
+        }
+        CrosstermKeyCode::Delete => KeyCode::Delete,
+        CrosstermKeyCode::Home => KeyCode::Home,
+        CrosstermKeyCode::End => KeyCode::End,
+        CrosstermKeyCode::PageUp => KeyCode::PageUp,
+        CrosstermKeyCode::PageDown => KeyCode::PageDown,
+        CrosstermKeyCode::Esc => KeyCode::Esc,
+        _ => return None,
+    };
+    Some(KeyEvent {
+        code,
+        ctrl: event.modifiers.contains(KeyModifiers::CONTROL),
+        alt: event.modifiers.contains(KeyModifiers::ALT),
+        shift: event.modifiers.contains(KeyModifiers::SHIFT),
+    })
+}
+
+/// Parse the raw bytes sent by a terminal emulator over a PTY into key events.
+///
+/// Only the sequences common terminal emulators emit for the keys the website uses are
+/// recognised (arrows, navigation, function keys, control characters), the rest is dropped.
+///
+/// Terminals send Alt + key as ESC followed by the key, in a single write, which is how the
+/// emacs preset gets `Alt+v`. An Esc typed by a person arrives in a read of its own, so ESC
+/// followed by a key in the same read is Alt + key. ESC followed by another ESC is an Esc.
+pub fn parse_terminal_input(bytes: &[u8]) -> Vec<KeyEvent> {
+    let text = String::from_utf8_lossy(bytes);
+    let mut chars = text.chars().peekable();
+    let mut events = Vec::new();
+    while let Some(c) = chars.next() {
+        let event = match c {
+            '\x1b' => match chars.peek() {
+                Some('[' | 'O') => {
+                    chars.next();
+                    parse_escape_sequence(&mut chars)
+                }
+                Some('\x1b') | None => Some(key(KeyCode::Esc)),
+                Some(_) => chars.next().map(|c| KeyEvent {
+                    alt: true,
+                    ..key(KeyCode::Char(c))
+                }),
+            },
+            '\r' => {
+                // Some clients end lines with CR LF, a single Enter
+                chars.next_if_eq(&'\n');
+                Some(key(KeyCode::Enter))
+            }
+            '\n' => Some(key(KeyCode::Enter)),
+            '\t' => Some(key(KeyCode::Tab)),
+            '\x7f' | '\x08' => Some(key(KeyCode::Backspace)),
+            '\x01'..='\x1a' => Some(KeyEvent {
+                ctrl: true,
+                ..key(KeyCode::Char(char::from(c as u8 - 1 + b'a')))
+            }),
+            c if c.is_control() => None,
+            c => Some(key(KeyCode::Char(c))),
+        };
+        events.extend(event);
+    }
+    events
+}
+
+/// Parse the rest of a `ESC [` or `ESC O` sequence, the introducer is already consumed.
+fn parse_escape_sequence(chars: &mut Peekable<Chars<'_>>) -> Option<KeyEvent> {
+    let mut params = String::new();
+    while let Some(&c) = chars.peek() {
+        if !c.is_ascii_digit() && c != ';' {
+            break;
+        }
+        params.push(c);
+        chars.next();
+    }
+    let mut params = params.split(';');
+    let number = params.next().unwrap_or_default();
+    // xterm encodes modifiers as 1 + (shift | alt << 1 | ctrl << 2)
+    let modifiers = params
+        .next()
+        .and_then(|m| m.parse::<u8>().ok())
+        .map_or(0, |m| m.saturating_sub(1));
+    let final_char = chars.next()?;
+    let code = match final_char {
+        'A' => KeyCode::Up,
+        'B' => KeyCode::Down,
+        'C' => KeyCode::Right,
+        'D' => KeyCode::Left,
+        'H' => KeyCode::Home,
+        'F' => KeyCode::End,
+        'Z' | 'I' => KeyCode::Tab,
+        'P' => KeyCode::F(1),
+        'Q' => KeyCode::F(2),
+        'R' => KeyCode::F(3),
+        'S' => KeyCode::F(4),
+        '~' => match number {
+            "1" | "7" => KeyCode::Home,
+            "3" => KeyCode::Delete,
+            "4" | "8" => KeyCode::End,
+            "5" => KeyCode::PageUp,
+            "6" => KeyCode::PageDown,
+            "15" => KeyCode::F(5),
+            "17" => KeyCode::F(6),
+            "18" => KeyCode::F(7),
+            "19" => KeyCode::F(8),
+            "20" => KeyCode::F(9),
+            "21" => KeyCode::F(10),
+            "23" => KeyCode::F(11),
+            "24" => KeyCode::F(12),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(KeyEvent {
+        code,
+        shift: modifiers & 1 != 0 || final_char == 'Z',
+        alt: modifiers & 2 != 0,
+        ctrl: modifiers & 4 != 0,
+    })
+}
+
+const fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        ctrl: false,
+        alt: false,
+        shift: false,
+    }
+}
+
+/// Ctrl + C always leaves the native frontends, whatever the scene is.
+pub fn is_interrupt(event: &KeyEvent) -> bool {
+    event.ctrl && event.code == KeyCode::Char('c')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Vec<KeyEvent> {
+        parse_terminal_input(input.as_bytes())
+    }
+
+    const fn with(code: KeyCode, shift: bool, alt: bool, ctrl: bool) -> KeyEvent {
+        KeyEvent {
+            code,
+            ctrl,
+            alt,
+            shift,
+        }
+    }
+
+    #[test]
+    fn arrows() {
+        // CSI in normal mode, SS3 in application mode
+        assert_eq!(
+            parse("\x1b[A\x1b[B"),
+            [key(KeyCode::Up), key(KeyCode::Down)]
+        );
+        assert_eq!(
+            parse("\x1bOC\x1bOD"),
+            [key(KeyCode::Right), key(KeyCode::Left)]
+        );
+        assert_eq!(
+            parse("\x1b[1;2A\x1b[1;5B"),
+            [
+                with(KeyCode::Up, true, false, false),
+                with(KeyCode::Down, false, false, true)
+            ]
+        );
+    }
+
+    #[test]
+    fn tilde_sequences() {
+        assert_eq!(
+            parse("\x1b[1~\x1b[4~\x1b[3~\x1b[5~\x1b[6~"),
+            [
+                key(KeyCode::Home),
+                key(KeyCode::End),
+                key(KeyCode::Delete),
+                key(KeyCode::PageUp),
+                key(KeyCode::PageDown),
+            ]
+        );
+        assert_eq!(
+            parse("\x1b[H\x1b[F"),
+            [key(KeyCode::Home), key(KeyCode::End)]
+        );
+        // Unknown sequences are dropped, the keys after them are kept
+        assert_eq!(parse("\x1b[99~q"), [key(KeyCode::Char('q'))]);
+    }
+
+    #[test]
+    fn lone_escape() {
+        assert_eq!(parse("\x1b"), [key(KeyCode::Esc)]);
+        assert_eq!(parse("\x1b\x1b"), [key(KeyCode::Esc), key(KeyCode::Esc)]);
+        assert_eq!(parse("\x1b\x1b[A"), [key(KeyCode::Esc), key(KeyCode::Up)]);
+    }
+
+    #[test]
+    fn escape_then_key_is_alt() {
+        assert_eq!(
+            parse("\x1bv"),
+            [with(KeyCode::Char('v'), false, true, false)]
+        );
+        assert_eq!(
+            parse("\x1b<"),
+            [with(KeyCode::Char('<'), false, true, false)]
+        );
+    }
+
+    #[test]
+    fn control_keys() {
+        let events = parse("\x03");
+        assert_eq!(events, [with(KeyCode::Char('c'), false, false, true)]);
+        assert!(is_interrupt(&events[0]));
+        assert_eq!(
+            parse("\t\x7f"),
+            [key(KeyCode::Tab), key(KeyCode::Backspace)]
+        );
+        assert_eq!(parse("\x1b[Z"), [with(KeyCode::Tab, true, false, false)]);
+    }
+
+    #[test]
+    fn enter() {
+        assert_eq!(parse("\r"), [key(KeyCode::Enter)]);
+        assert_eq!(parse("\n"), [key(KeyCode::Enter)]);
+        assert_eq!(parse("\r\n"), [key(KeyCode::Enter)]);
+        assert_eq!(parse("\r\r"), [key(KeyCode::Enter), key(KeyCode::Enter)]);
+    }
+
+    #[test]
+    fn multibyte_characters() {
+        assert_eq!(
+            parse("é♥"),
+            [key(KeyCode::Char('é')), key(KeyCode::Char('♥'))]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use ratatui::DefaultTerminal;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+use website::state::{State, ui};
+
+mod input;
+mod ssh;
+
+/// How long to wait for an input before drawing the next frame
+const FRAME_TIME: Duration = Duration::from_millis(16);
+
+/// Address the SSH server listens on when `--listen` is not given
+const DEFAULT_SSH_ADDRESS: &str = "0.0.0.0:2222";
+
+const USAGE: &str = "Usage:
+  website-tui                                    Explore the website in this terminal
+  website-tui ssh [--listen <ADDR>] [--key <PATH>]
+                                                 Serve the website to SSH clients";
+
+/// How the binary was asked to run
+enum Mode {
+    Local,
+    Ssh {
+        address: String,
+        host_key: Option<PathBuf>,
+    },
+}
+
+impl Mode {
+    fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        match args.next().as_deref() {
+            None => Ok(Self::Local),
+            Some("ssh") => {
+                let mut address = DEFAULT_SSH_ADDRESS.to_string();
+                let mut host_key = None;
+                while let Some(arg) = args.next() {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("Missing value for '{arg}'"))?;
+                    match arg.as_str() {
+                        "--listen" => address = value,
+                        "--key" => host_key = Some(PathBuf::from(value)),
+                        _ => return Err(format!("Unknown option '{arg}'")),
+                    }
+                }
+                Ok(Self::Ssh { address, host_key })
+            }
+            Some(other) => Err(format!("Unknown command '{other}'")),
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mode = Mode::from_args(std::env::args().skip(1)).map_err(|e| {
+        eprintln!("{e}\n\n{USAGE}");
+        io::Error::new(io::ErrorKind::InvalidInput, e)
+    })?;
+    match mode {
+        Mode::Local => {
+            let mut terminal = ratatui::init();
+            let result = run(&mut terminal);
+            ratatui::restore();
+            result
+        }
+        Mode::Ssh { address, host_key } => ssh::serve(&address, host_key.as_deref()),
+    }
+}
+
+fn run(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let mut state = State::default();
+    loop {
+        terminal.draw(|f| ui(f, &mut state))?;
+        if state.take_exited() {
+            return Ok(());
+        }
+        if !event::poll(FRAME_TIME)? {
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            if let Some(key) = input::to_key_event(&key) {
+                if input::is_interrupt(&key) {
+                    return Ok(());
+                }
+                state.on_key_event(&key);
+            }
+        }
+    }
+}
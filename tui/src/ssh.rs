@@ -0,0 +1,339 @@
+//! SSH server mode: every connection gets its own website session rendered over its PTY.
+//!
+//! `State` holds tachyonfx effects, which are not `Send`, so each session lives on a
+//! dedicated thread. The async SSH handler only forwards input and resize events to it,
+//! and the session thread sends the rendered bytes back through a channel. At most
+//! [`MAX_SESSIONS`] sessions run at once, the channels opened past it are refused.
+
+use crate::{FRAME_TIME, input};
+use ratatui::backend::CrosstermBackend;
+use ratatui::crossterm::{cursor, execute, terminal};
+use ratatui::layout::Rect;
+use ratatui::{Terminal, TerminalOptions, Viewport};
+use russh::keys::ssh_key::{Algorithm, HashAlg};
+use russh::keys::{PrivateKey, PublicKey, load_secret_key};
+use russh::server::{Auth, ChannelOpenHandle, Config, Handle, Handler, Msg, Server, Session};
+use russh::{Channel, ChannelId, ChannelOpenFailure, Pty};
+use std::io::{self, Write};
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, mpsc};
+use std::thread;
+use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+use website::state::{State, ui};
+
+/// Most sessions running at once, each one holds a thread
+const MAX_SESSIONS: usize = 64;
+
+/// Events forwarded from the SSH connection to its session thread
+enum SessionEvent {
+    Input(Vec<u8>),
+    Resize(Rect),
+}
+
+/// Messages sent from the session thread back to the SSH connection
+enum SessionOutput {
+    Data(Vec<u8>),
+    Close,
+}
+
+/// Start the SSH server and serve sessions until the process is stopped.
+///
+/// The host key is loaded from `host_key` when given, otherwise a throwaway Ed25519 key is
+/// generated, which makes clients complain about a changed host key on every restart.
+pub fn serve(address: &str, host_key: Option<&Path>) -> io::Result<()> {
+    let key = if let Some(path) = host_key {
+        load_secret_key(path, None).map_err(io::Error::other)?
+    } else {
+        eprintln!("No --key given, using a temporary host key");
+        PrivateKey::random(&mut rand::rng(), Algorithm::Ed25519).map_err(io::Error::other)?
+    };
+    eprintln!(
+        "Serving the website over SSH on {address} ({})",
+        key.public_key().fingerprint(HashAlg::Sha256)
+    );
+    let config = Config {
+        keys: vec![key],
+        auth_rejection_time_initial: Some(std::time::Duration::ZERO),
+        nodelay: true,
+        ..Config::default()
+    };
+    tokio::runtime::Runtime::new()?.block_on(async {
+        let mut server = SshServer::default();
+        server.run_on_address(Arc::new(config), address).await
+    })
+}
+
+#[derive(Default)]
+struct SshServer {
+    /// Sessions running, shared by every connection
+    sessions: Arc<AtomicUsize>,
+}
+
+impl Server for SshServer {
+    type Handler = SshSession;
+
+    fn new_client(&mut self, _peer_addr: Option<SocketAddr>) -> SshSession {
+        SshSession {
+            events: None,
+            sessions: self.sessions.clone(),
+        }
+    }
+}
+
+/// Connection handler, forwarding events to the session thread once a channel is open
+struct SshSession {
+    events: Option<mpsc::Sender<SessionEvent>>,
+    sessions: Arc<AtomicUsize>,
+}
+
+impl SshSession {
+    fn send(&self, event: SessionEvent) {
+        if let Some(events) = &self.events {
+            // The session thread is gone only once the visitor left, nothing to do then
+            let _ = events.send(event);
+        }
+    }
+}
+
+impl Handler for SshSession {
+    type Error = russh::Error;
+
+    // The website is public, anybody is welcome whatever the credentials
+    async fn auth_none(&mut self, _user: &str) -> Result<Auth, Self::Error> {
+        Ok(Auth::Accept)
+    }
+
+    async fn auth_password(&mut self, _user: &str, _password: &str) -> Result<Auth, Self::Error> {
+        Ok(Auth::Accept)
+    }
+
+    async fn auth_publickey(
+        &mut self,
+        _user: &str,
+        _public_key: &PublicKey,
+    ) -> Result<Auth, Self::Error> {
+        Ok(Auth::Accept)
+    }
+
+    async fn channel_open_session(
+        &mut self,
+        channel: Channel<Msg>,
+        reply: ChannelOpenHandle,
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        // A single website session per connection
+        if self.events.is_some() {
+            return Ok(());
+        }
+        let Some(slot) = SessionSlot::take(&self.sessions) else {
+            eprintln!("{MAX_SESSIONS} SSH sessions running, refusing a new one");
+            reply.reject(ChannelOpenFailure::ResourceShortage).await;
+            return Ok(());
+        };
+        self.events = Some(spawn_session(session.handle(), channel.id(), slot));
+        reply.accept().await;
+        Ok(())
+    }
+
+    async fn pty_request(
+        &mut self,
+        channel: ChannelId,
+        _term: &str,
+        col_width: u32,
+        row_height: u32,
+        _pix_width: u32,
+        _pix_height: u32,
+        _modes: &[(Pty, u32)],
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        self.send(SessionEvent::Resize(to_area(col_width, row_height)));
+        session.channel_success(channel)?;
+        Ok(())
+    }
+
+    async fn shell_request(
+        &mut self,
+        channel: ChannelId,
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        session.channel_success(channel)?;
+        Ok(())
+    }
+
+    async fn data(
+        &mut self,
+        _channel: ChannelId,
+        data: &[u8],
+        _session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        self.send(SessionEvent::Input(data.to_vec()));
+        Ok(())
+    }
+
+    async fn window_change_request(
+        &mut self,
+        _channel: ChannelId,
+        col_width: u32,
+        row_height: u32,
+        _pix_width: u32,
+        _pix_height: u32,
+        _session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        self.send(SessionEvent::Resize(to_area(col_width, row_height)));
+        Ok(())
+    }
+}
+
+fn to_area(col_width: u32, row_height: u32) -> Rect {
+    Rect::new(
+        0,
+        0,
+        u16::try_from(col_width).unwrap_or(u16::MAX),
+        u16::try_from(row_height).unwrap_or(u16::MAX),
+    )
+}
+
+/// A place among the [`MAX_SESSIONS`] running sessions, given back when dropped.
+struct SessionSlot(Arc<AtomicUsize>);
+
+impl SessionSlot {
+    /// Take a place, unless every one is taken.
+    fn take(sessions: &Arc<AtomicUsize>) -> Option<Self> {
+        sessions
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |running| {
+                (running < MAX_SESSIONS).then_some(running + 1)
+            })
+            .ok()?;
+        Some(Self(sessions.clone()))
+    }
+}
+
+impl Drop for SessionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Closes the channel once dropped, so that a panicking session does not leave the client
+/// hanging.
+struct CloseOnDrop(UnboundedSender<SessionOutput>);
+
+impl Drop for CloseOnDrop {
+    fn drop(&mut self) {
+        let _ = self.0.send(SessionOutput::Close);
+    }
+}
+
+/// Spawn the thread running the website for a single channel, in the place `slot`.
+///
+/// Returns the sender used to forward the channel events to it. The channel is closed
+/// once the visitor interrupts the session, dropping the sender ends the thread.
+fn spawn_session(
+    handle: Handle,
+    channel: ChannelId,
+    slot: SessionSlot,
+) -> mpsc::Sender<SessionEvent> {
+    let (output_sender, mut output_receiver) = unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(output) = output_receiver.recv().await {
+            match output {
+                SessionOutput::Data(data) => {
+                    if handle.data(channel, data).await.is_err() {
+                        break;
+                    }
+                }
+                SessionOutput::Close => {
+                    let _ = handle.exit_status_request(channel, 0).await;
+                    let _ = handle.eof(channel).await;
+                    let _ = handle.close(channel).await;
+                    break;
+                }
+            }
+        }
+    });
+
+    let (event_sender, event_receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // Both are dropped when the thread ends, panicking or not
+        let _close = CloseOnDrop(output_sender.clone());
+        let _slot = slot;
+        if let Err(e) = run_session(&event_receiver, output_sender) {
+            eprintln!("SSH session failed: {e}");
+        }
+    });
+    event_sender
+}
+
+/// Draw the website for one visitor until they leave or interrupt it.
+fn run_session(
+    events: &mpsc::Receiver<SessionEvent>,
+    output: UnboundedSender<SessionOutput>,
+) -> io::Result<()> {
+    let mut writer = ChannelWriter::new(output);
+    execute!(writer, terminal::EnterAlternateScreen, cursor::Hide)?;
+    // The area is only known once the client requests a PTY
+    let options = TerminalOptions {
+        viewport: Viewport::Fixed(Rect::default()),
+    };
+    let mut terminal = Terminal::with_options(CrosstermBackend::new(writer), options)?;
+    let mut state = State::default();
+    loop {
+        match events.recv_timeout(FRAME_TIME) {
+            Ok(SessionEvent::Input(bytes)) => {
+                let keys = input::parse_terminal_input(&bytes);
+                if keys.iter().any(input::is_interrupt) {
+                    break;
+                }
+                for key in &keys {
+                    state.on_key_event(key);
+                }
+            }
+            Ok(SessionEvent::Resize(area)) => terminal.resize(area)?,
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            // The connection is gone, there is nobody left to restore the screen for
+            Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+        terminal.draw(|f| ui(f, &mut state))?;
+        if state.take_exited() {
+            break;
+        }
+    }
+    execute!(
+        terminal.backend_mut(),
+        terminal::LeaveAlternateScreen,
+        cursor::Show
+    )
+}
+
+/// Buffers whatever the terminal backend writes and ships it to the SSH channel on flush.
+struct ChannelWriter {
+    output: UnboundedSender<SessionOutput>,
+    buffer: Vec<u8>,
+}
+
+impl ChannelWriter {
+    const fn new(output: UnboundedSender<SessionOutput>) -> Self {
+        Self {
+            output,
+            buffer: Vec::new(),
+        }
+    }
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        self.output
+            .send(SessionOutput::Data(std::mem::take(&mut self.buffer)))
+            .map_err(|e| io::Error::new(io::ErrorKind::BrokenPipe, e.to_string()))
+    }
+}